@@ -7,13 +7,18 @@
 //! export API_KEY=YOUR_API_KEY
 //! export ORDER_ID=order-1
 //! export ORDER_USER_ID=order-user-1
+//! # Optionally apply a decision to the order before checking its status
+//! export DECISION_ID=block_order_payment_abuse
 //!
 //! cargo run --example decision --features=reqwest
 //! ```
 
 use std::env;
 
-use sift_science::{decisions::Entity, Client};
+use sift_science::{
+    decisions::{DecisionRequest, Entity, Source},
+    Client,
+};
 use tracing::{info, Level};
 
 #[tokio::main]
@@ -28,6 +33,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Instantiate sift client
     let sift = Client::new(api_key, reqwest::Client::new()).with_account_id(account_id);
 
+    // Apply a decision
+    if let Ok(decision_id) = env::var("DECISION_ID") {
+        let decision = sift
+            .apply_decision(
+                Entity::Order {
+                    order_id: order_id.clone(),
+                    user_id: user_id.clone(),
+                },
+                DecisionRequest {
+                    decision_id,
+                    source: Source::AutomatedRule,
+                    analyst: None,
+                    time: None,
+                    description: Some("applied from the decision example".into()),
+                },
+            )
+            .await?;
+
+        info!(?decision, "applied decision");
+    }
+
     // Get a decision status
     let status = sift
         .decision_status(Entity::Order { order_id, user_id })
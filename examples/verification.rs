@@ -12,8 +12,8 @@
 //! ```
 use sift_science::{
     events::{
-        Event, EventOptions, LoginProperties, LoginStatus, VerificationReason, VerificationType,
-        VerifiedEvent,
+        Event, EventOptions, LoginProperties, LoginStatus, VerificationReason, VerificationStatus,
+        VerificationType, VerifiedEvent,
     },
     verification::{CheckOptions, SendRequest, SendRequestEvent},
     Client,
@@ -82,11 +82,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initiate a verification
     let response = sift
         .check_verification(
-            user_id,
+            user_id.clone(),
             code,
             CheckOptions {
                 verified_event: Some(VerifiedEvent::Login),
-                verified_entity_id: Some(session_id),
+                verified_entity_id: Some(session_id.clone()),
                 ..Default::default()
             },
         )
@@ -94,5 +94,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!(?response, "Got sift verification check response");
 
+    // Record the verification outcome
+    let status = if response.is_ok() {
+        VerificationStatus::Success
+    } else {
+        VerificationStatus::Failure
+    };
+    sift.complete_verification(
+        user_id,
+        session_id.clone(),
+        VerifiedEvent::Login,
+        Some(session_id),
+        status,
+    )
+    .await?;
+
     Ok(())
 }
@@ -1,34 +1,49 @@
 #[cfg(feature = "decisions")]
 use crate::decisions::{
-    self, Decision, DecisionPage, DecisionRequest, DecisionResult, DecisionStatus, Decisions,
-    Entity, EntityType,
+    self, Decision, DecisionData, DecisionPage, DecisionRequest, DecisionResult, DecisionStatus,
+    Decisions, Entity, EntityType, ListDecisionsOptions,
 };
+#[cfg(feature = "verification")]
+use crate::events::{VerificationProperties, VerificationStatus, VerifiedEvent};
+#[cfg(all(feature = "labels", feature = "decisions"))]
+use crate::labels::LabelRoute;
 #[cfg(feature = "labels")]
-use crate::labels::{LabelOptions, LabelProperties};
+use crate::labels::{LabelManyOptions, LabelOptions, LabelProperties};
 #[cfg(feature = "score")]
 use crate::score::{ScoreOptions, ScoreQueryParams};
 #[cfg(feature = "verification")]
 use crate::verification::{
-    self, CheckOptions, CheckRequest, CheckResponse, ResendRequest, SendRequest, SendResponse,
+    CheckOptions, CheckRequest, CheckResponse, ResendOptions, ResendRequest, SendOptions,
+    SendRequest, SendResponse,
 };
 #[cfg(feature = "webhooks")]
 use crate::webhooks::{self, Webhook, WebhookRequest, WebhookResponse, WebhooksResponse};
+#[cfg(feature = "workflows")]
+use crate::workflows::{self, WorkflowRun, WorkflowRunResult};
+#[cfg(feature = "decisions")]
+use crate::Paginator;
 use crate::{
-    common::{abuse_type_serialize, AbuseType},
-    events::{self, Event, EventOptions, EventQueryParams, EventResponse, ScoreResponse, Scores},
-    Error, Result,
+    common::{abuse_type_serialize, hash_user_id, AbuseType, Redacted},
+    events::{
+        Event, EventOptions, EventQueryParams, EventResponse, ScoreResponse, Scores, TrackOutcome,
+    },
+    Error, Metrics, RequestEnd, Result, RetryPolicy,
 };
-use async_trait::async_trait;
 #[cfg(any(feature = "awc", feature = "awc3", feature = "reqwest"))]
 use futures::future::TryFutureExt;
+#[cfg(feature = "decisions")]
+use futures::stream::{Stream, TryStreamExt};
 use serde::Serialize;
 use std::borrow::Cow;
 use std::fmt;
-use std::time::Duration;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::{debug, instrument, trace};
 
 const SIFT_ORIGIN: &str = "https://api.sift.com";
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
 
 /// A client for the Sift Science API
 ///
@@ -37,6 +52,10 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
 ///
 /// Once you have a client set up, you can access the service's endpoints by calling the
 /// appropriate method on [Client].
+///
+/// Build one client and share it, rather than creating an HTTP client per request. Clients are
+/// cloneable when their HTTP client is, and clones of pooled HTTP clients like `reqwest::Client`
+/// share the underlying connection pool.
 pub struct Client<T> {
     /// Sift api key
     pub api_key: String,
@@ -53,15 +72,53 @@ pub struct Client<T> {
 
     /// Sift api origin
     pub origin: String,
+
+    /// Automatic retry configuration
+    ///
+    /// Requests are not retried when unset.
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// Timeout used when a call does not override it
+    pub default_timeout: Duration,
+
+    /// Abuse types used for scoring when a call does not specify any
+    pub default_abuse_types: Option<Vec<AbuseType>>,
+
+    /// Mask PII in logged request bodies
+    ///
+    /// When set, `$user_email`, `$phone`, `$card_bin`, `$card_last4` and `$avs_result_code` are
+    /// masked wherever they appear in logged bodies. `$api_key` is always masked. Requests sent to
+    /// Sift are unchanged. Defaults to `true`.
+    pub redact_pii: bool,
+
+    /// Hook for recording request metrics
+    pub metrics: Option<Arc<dyn Metrics>>,
+
+    /// Gzip event bodies larger than `compression_threshold` bytes
+    ///
+    /// Only applies when the HTTP client [supports gzip]. Defaults to `false`.
+    ///
+    /// [supports gzip]: HttpClient::supports_gzip
+    pub compress_requests: bool,
+
+    /// Size in bytes above which event bodies are compressed. Defaults to 1 KiB.
+    pub compression_threshold: usize,
 }
 
 impl<T: Clone> Clone for Client<T> {
     fn clone(&self) -> Self {
         Client {
             api_key: self.api_key.clone(),
-            account_id: None,
+            account_id: self.account_id.clone(),
             http_client: self.http_client.clone(),
             origin: self.origin.clone(),
+            retry_policy: self.retry_policy.clone(),
+            default_timeout: self.default_timeout,
+            default_abuse_types: self.default_abuse_types.clone(),
+            redact_pii: self.redact_pii,
+            metrics: self.metrics.clone(),
+            compress_requests: self.compress_requests,
+            compression_threshold: self.compression_threshold,
         }
     }
 }
@@ -74,84 +131,434 @@ impl<T: HttpClient> Client<T> {
             account_id: None,
             http_client,
             origin: SIFT_ORIGIN.into(),
+            retry_policy: None,
+            default_timeout: DEFAULT_TIMEOUT,
+            default_abuse_types: None,
+            redact_pii: true,
+            metrics: None,
+            compress_requests: false,
+            compression_threshold: DEFAULT_COMPRESSION_THRESHOLD,
+        }
+    }
+
+    /// Configure a new sift client with a given api key and HTTP client.
+    pub fn builder(api_key: impl Into<String>, http_client: T) -> ClientBuilder<T> {
+        ClientBuilder {
+            client: Client::new(api_key, http_client),
         }
     }
 
     /// Override the sift api origin.
     ///
-    /// Useful for testing environments and debugging.
+    /// Useful for testing environments and debugging. The client is returned rather than
+    /// modified in place, so ignoring it is a warning:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use sift_science::{Client, HttpClient};
+    ///
+    /// fn point_at_sandbox<T: HttpClient>(sift: Client<T>) {
+    ///     sift.with_origin("http://localhost:8080");
+    /// }
+    /// ```
+    #[must_use]
     pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
         self.origin = origin.into();
         self
     }
 
     /// Override the sift account id.
+    #[must_use]
     pub fn with_account_id(mut self, account_id: impl Into<String>) -> Self {
         self.account_id = Some(account_id.into());
         self
     }
 
+    /// Automatically retry requests that fail with transient errors.
+    ///
+    /// See [RetryPolicy] for which requests and errors are retried.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Replace the HTTP client, keeping the rest of the configuration.
+    ///
+    /// Useful for swapping in a mock transport, or wrapping the transport in a decorator.
+    pub fn map_http_client<U: HttpClient>(self, f: impl FnOnce(T) -> U) -> Client<U> {
+        Client {
+            api_key: self.api_key,
+            account_id: self.account_id,
+            http_client: f(self.http_client),
+            origin: self.origin,
+            retry_policy: self.retry_policy,
+            default_timeout: self.default_timeout,
+            default_abuse_types: self.default_abuse_types,
+            redact_pii: self.redact_pii,
+            metrics: self.metrics,
+            compress_requests: self.compress_requests,
+            compression_threshold: self.compression_threshold,
+        }
+    }
+
+    /// Report the start, end and duration of every API call to a [Metrics] hook.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    // The account id, required by the webhook and decision APIs
+    fn resolved_account_id(&self) -> Result<&str> {
+        self.account_id
+            .as_deref()
+            .ok_or_else(|| Error::Server("account id not specified".into()))
+    }
+
+    // A request body for logging, masking PII unless disabled
+    fn redacted<'a>(&self, body: &'a serde_json::Value) -> Redacted<'a> {
+        Redacted {
+            body,
+            redact: self.redact_pii,
+        }
+    }
+
+    // Serialize a body to be gzipped by the HTTP client, when it is large enough and compression
+    // is enabled and supported
+    fn compress(&self, body: &serde_json::Value) -> Result<Option<Vec<u8>>> {
+        if !self.compress_requests || !self.http_client.supports_gzip() {
+            return Ok(None);
+        }
+
+        let json = serde_json::to_vec(body)?;
+        Ok((json.len() > self.compression_threshold).then_some(json))
+    }
+
+    // Run a request, reporting it to the metrics hook
+    async fn measured<Fut, R>(&self, endpoint: &'static str, request: Fut) -> Result<R>
+    where
+        Fut: Future<Output = Result<R>>,
+    {
+        let metrics = match &self.metrics {
+            Some(metrics) => metrics,
+            None => return request.await,
+        };
+
+        metrics.on_request_start(endpoint);
+        let start = Instant::now();
+        let result = request.await;
+        metrics.on_request_end(RequestEnd {
+            endpoint,
+            status: result.as_ref().map(|_| ()),
+            duration: start.elapsed(),
+        });
+
+        result
+    }
+
+    // Run a request, retrying transient failures according to the retry policy
+    async fn with_retries<F, Fut, R>(&self, endpoint: &'static str, mut request: F) -> Result<R>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let policy = match &self.retry_policy {
+            Some(policy) => policy,
+            None => return self.measured(endpoint, request()).await,
+        };
+
+        self.measured(endpoint, async {
+            let mut retry = 0;
+            loop {
+                match request().await {
+                    Err(err) if retry < policy.max_retries && policy.is_retryable(&err) => {
+                        let delay = policy.retry_delay(retry, &err);
+                        tracing::warn!(?err, retry, ?delay, "retrying sift request");
+                        (policy.sleep)(delay).await;
+                        retry += 1;
+                    }
+                    result => return result,
+                }
+            }
+        })
+        .await
+    }
+
+    // Run a request, retrying it only if the caller opted in, for requests that aren't safe to
+    // repeat by default
+    async fn with_retries_if<F, Fut, R>(
+        &self,
+        retry: bool,
+        endpoint: &'static str,
+        mut request: F,
+    ) -> Result<R>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        if retry {
+            self.with_retries(endpoint, request).await
+        } else {
+            self.measured(endpoint, request()).await
+        }
+    }
+
     /// Sends an event to the Sift Science Events API.
-    #[instrument(skip(self, event, options))]
+    ///
+    /// The event is checked with [Event::validate] before it is sent.
+    ///
+    /// The tracing span records the `endpoint`, the `api_version` and a `user` hashed from the
+    /// user id, so traces can be filtered without recording the user id itself.
+    ///
+    /// Nothing is sent until the returned future is awaited, so dropping it, or ignoring the
+    /// result, is a warning:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use sift_science::{
+    ///     events::{Event, EventOptions},
+    ///     Client, HttpClient,
+    /// };
+    ///
+    /// fn track_and_forget<T: HttpClient>(sift: &Client<T>, event: Event) {
+    ///     sift.track(event, EventOptions::default());
+    /// }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use sift_science::{
+    ///     events::{Event, EventOptions},
+    ///     Client, HttpClient,
+    /// };
+    ///
+    /// async fn track_and_ignore<T: HttpClient>(sift: &Client<T>, event: Event) {
+    ///     sift.track(event, EventOptions::default()).await;
+    /// }
+    /// ```
+    #[instrument(
+        skip(self, event, options),
+        fields(
+            endpoint = "track",
+            api_version = %options.version.unwrap_or_default(),
+            user = event.user_id().map(hash_user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn track(&self, event: Event, options: EventOptions) -> Result<Option<Scores>> {
-        let version = options.version.unwrap_or(events::ApiVersion::V205);
+        self.track_with_meta(event, options)
+            .await
+            .map(|(scores, _)| scores)
+    }
+
+    /// Sends an event to the Sift Science Events API, and returns the synchronous scores for the
+    /// given abuse types.
+    ///
+    /// Unlike [Client::track], `return_score` is always set, and a response without scores is an
+    /// error rather than `Ok(None)`.
+    #[instrument(
+        skip(self, event),
+        fields(
+            endpoint = "track",
+            api_version = %crate::events::ApiVersion::default(),
+            user = event.user_id().map(hash_user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn track_scored(&self, event: Event, abuse_types: Vec<AbuseType>) -> Result<Scores> {
+        self.track(event, EventOptions::scored(abuse_types))
+            .await?
+            .ok_or_else(|| {
+                Error::Server("Expected a score, but the event response contained none".into())
+            })
+    }
+
+    /// Sends an event to the Sift Science Events API, returning the response metadata along with
+    /// any scores.
+    ///
+    /// The [ResponseMeta] exposes Sift's rate-limit headers, e.g. to apply back-pressure to an
+    /// event pipeline.
+    ///
+    /// The metadata is only returned with successful responses, and is lost when the request
+    /// fails. A rate limited request fails with an [Error::RateLimited] instead, which carries the
+    /// `Retry-After` delay.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [Client::track], without returning the response metadata.
+    #[instrument(
+        skip(self, event, options),
+        fields(
+            endpoint = "track",
+            api_version = %options.version.unwrap_or_default(),
+            user = event.user_id().map(hash_user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn track_with_meta(
+        &self,
+        event: Event,
+        options: EventOptions,
+    ) -> Result<(Option<Scores>, ResponseMeta)> {
+        let (score_response, meta) = self.send_event(event, options).await?;
+        Ok((score_response.and_then(|response| response.scores), meta))
+    }
+
+    /// Sends an event to the Sift Science Events API, returning the scores, triggered actions and
+    /// workflow statuses.
+    ///
+    /// Set [EventOptions::return_action] and [EventOptions::return_workflow_status] to have Sift
+    /// include actions and workflow statuses in the response.
+    #[instrument(
+        skip(self, event, options),
+        fields(
+            endpoint = "track",
+            api_version = %options.version.unwrap_or_default(),
+            user = event.user_id().map(hash_user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn track_full(&self, event: Event, options: EventOptions) -> Result<TrackOutcome> {
+        let (score_response, _) = self.send_event(event, options).await?;
+        Ok(score_response.map(TrackOutcome::from).unwrap_or_default())
+    }
+
+    /// Sends an event to the Sift Science Events API, and returns the response body as is.
+    ///
+    /// Useful for debugging, or for response fields this library doesn't model yet. The body is
+    /// not checked, so a rejected event is `Ok` with its error `status`; transport failures and
+    /// HTTP errors are still returned as errors. Returns `Ok(None)` when Sift replies without a
+    /// body.
+    #[instrument(
+        skip(self, event, options),
+        fields(
+            endpoint = "track",
+            api_version = %options.version.unwrap_or_default(),
+            user = event.user_id().map(hash_user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn track_raw(
+        &self,
+        event: Event,
+        options: EventOptions,
+    ) -> Result<Option<serde_json::Value>> {
+        let (sift_response, _) = self.send_event_raw(event, options).await?;
+        trace!(?sift_response, "sift event API response");
+
+        Ok(sift_response)
+    }
+
+    /// Prepares the request [Client::track] would send for an event, without sending it.
+    ///
+    /// Returns the url, the JSON body and the query params, e.g. to snapshot test event payloads
+    /// before sending them to Sift. The event is checked with [Event::validate], and the client's
+    /// defaults are applied to the options just as they are when tracking.
+    pub fn build_event_request(
+        &self,
+        event: Event,
+        mut options: EventOptions,
+    ) -> Result<(String, serde_json::Value, QueryParams)> {
+        event.validate()?;
+
+        if options.abuse_types.is_none() {
+            options.abuse_types = self.default_abuse_types.clone();
+        }
+
+        let version = options.version.unwrap_or_default();
         let path = options.path.clone().unwrap_or(Cow::Borrowed("events"));
-        let timeout = options.timeout.unwrap_or(DEFAULT_TIMEOUT);
 
         let url = format!("{}/{}/{}", self.origin, version, path);
-        let mut body = serde_json::json!(&event);
+        let mut body = to_json(&event)?;
         body["$api_key"] = serde_json::json!(options.api_key.as_deref().unwrap_or(&self.api_key));
-        trace!(?event, ?options, "preparing event");
+        trace!(event = ?self.redacted(&body), ?options, "preparing event");
+
+        Ok((url, body, EventQueryParams::from(options).into()))
+    }
 
-        let query_params = EventQueryParams::from(options);
+    async fn send_event_raw(
+        &self,
+        event: Event,
+        mut options: EventOptions,
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let headers = options.extra_headers.take().unwrap_or_default();
+        let timeout = options.timeout.unwrap_or(self.default_timeout);
+        let retry = options.retry.unwrap_or(false);
+
+        let (url, body, query_params) = self.build_event_request(event, options)?;
         debug!(
             ?url,
             query_params = ?serde_urlencoded::to_string(&query_params),
-            body = ?serde_json::to_string(&body),
+            body = ?self.redacted(&body),
             "tracking event"
         );
+        let gzip = self.compress(&body)?;
+
+        self.with_retries_if(retry, "track", || async {
+            match &gzip {
+                Some(json) => {
+                    self.http_client
+                        .post_gzip(&url, Some(&query_params), json, timeout, None, &headers)
+                        .await
+                }
+                None => {
+                    self.http_client
+                        .post_with_meta(
+                            &url,
+                            Some(&query_params),
+                            Some(&body),
+                            timeout,
+                            None,
+                            &headers,
+                        )
+                        .await
+                }
+            }
+        })
+        .await
+    }
 
-        let sift_response = self
-            .http_client
-            .post(&url, Some(&query_params.into()), Some(&body), timeout, None)
-            .await?;
+    async fn send_event(
+        &self,
+        event: Event,
+        options: EventOptions,
+    ) -> Result<(Option<ScoreResponse>, ResponseMeta)> {
+        let (sift_response, meta) = self.send_event_raw(event, options).await?;
 
         // if no response options set, there will be no body
         if sift_response.is_none() {
-            return Ok(None);
+            return Ok((None, meta));
         }
         let event_json = sift_response.unwrap();
         trace!(?event_json, "sift event API response");
 
-        // Else there is a (nested) set of success or failure responses in the json body
-        // ¯\_(ツ)_/¯
-        match serde_json::from_value(event_json)? {
-            EventResponse {
-                score_response:
-                    Some(ScoreResponse {
-                        scores: Some(scores),
-                        ..
-                    }),
-                ..
-            } => Ok(Some(scores)),
-            EventResponse {
+        // Else there is a (nested) set of success or failure responses in the json body. The outer
+        // status is whether the event was accepted, and takes precedence over the inner status of
+        // the scores.
+        let EventResponse {
+            status,
+            error_message,
+            score_response,
+        } = serde_json::from_value(event_json)?;
+
+        if status != 0 {
+            return Err(Error::Request {
+                status: status.into(),
+                error_message,
+            });
+        }
+
+        match score_response {
+            Some(ScoreResponse {
                 status,
                 error_message,
                 ..
-            }
-            | EventResponse {
-                score_response:
-                    Some(ScoreResponse {
-                        status,
-                        error_message,
-                        ..
-                    }),
-                ..
-            } if status != 0 => Err(Error::Request {
-                status,
+            }) if status != 0 => Err(Error::Request {
+                status: status.into(),
                 error_message,
             }),
-            _ => Ok(None),
+            score_response => Ok((score_response, meta)),
         }
     }
 
@@ -159,33 +566,140 @@ impl<T: HttpClient> Client<T> {
     ///
     /// See <https://sift.com/developers/docs/curl/score-api/get-score/overview>
     #[cfg(feature = "score")]
-    #[instrument(skip(self, opts))]
-    pub async fn get_user_score<U>(
+    #[instrument(
+        skip(self, user_id, opts),
+        fields(
+            endpoint = "get_user_score",
+            api_version = %opts.version.unwrap_or_default(),
+            user = hash_user_id(user_id.as_ref()),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn get_user_score<U>(&self, user_id: U, opts: ScoreOptions) -> Result<ScoreResponse>
+    where
+        U: AsRef<str> + fmt::Debug,
+    {
+        self.fetch_score("get_user_score", "users", user_id.as_ref(), opts)
+            .await
+    }
+
+    /// Rescores the specified user for the specified abuse types and returns the resulting
+    /// score(s).
+    ///
+    /// See <https://sift.com/developers/docs/curl/score-api/rescore>
+    #[cfg(feature = "score")]
+    #[instrument(
+        skip(self, user_id, opts),
+        fields(
+            endpoint = "rescore_user",
+            api_version = %opts.version.unwrap_or_default(),
+            user = hash_user_id(user_id.as_ref()),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn rescore_user<U>(&self, user_id: U, opts: ScoreOptions) -> Result<ScoreResponse>
+    where
+        U: AsRef<str> + fmt::Debug,
+    {
+        self.rescore("rescore_user", "users", user_id.as_ref(), opts)
+            .await
+    }
+
+    /// Fetches the latest score(s) computed for the specified session and abuse types.
+    ///
+    /// Use this to assess risk by the `$session_id` sent with events, such as for visitors that
+    /// have not logged in. The path is `sessions/{session_id}/score`.
+    #[cfg(feature = "score")]
+    #[instrument(
+        skip(self, session_id, opts),
+        fields(
+            endpoint = "get_session_score",
+            api_version = %opts.version.unwrap_or_default(),
+            session = hash_user_id(session_id.as_ref()),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn get_session_score<S>(
         &self,
-        user_id: U,
-        mut opts: ScoreOptions,
+        session_id: S,
+        opts: ScoreOptions,
     ) -> Result<ScoreResponse>
     where
-        U: AsRef<str> + fmt::Debug,
+        S: AsRef<str> + fmt::Debug,
     {
-        let version = opts.version.unwrap_or(events::ApiVersion::V205);
-        let path_prefix = opts.path_prefix.unwrap_or("users");
-        let path_suffix = opts.path_prefix.unwrap_or("score");
-        let timeout = opts.timeout.unwrap_or(DEFAULT_TIMEOUT);
-        let user_id = urlencoding::encode(user_id.as_ref()).to_string();
+        self.fetch_score("get_session_score", "sessions", session_id.as_ref(), opts)
+            .await
+    }
 
-        let url = format!(
+    /// Rescores the specified session for the specified abuse types and returns the resulting
+    /// score(s).
+    #[cfg(feature = "score")]
+    #[instrument(
+        skip(self, session_id, opts),
+        fields(
+            endpoint = "rescore_session",
+            api_version = %opts.version.unwrap_or_default(),
+            session = hash_user_id(session_id.as_ref()),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn rescore_session<S>(
+        &self,
+        session_id: S,
+        opts: ScoreOptions,
+    ) -> Result<ScoreResponse>
+    where
+        S: AsRef<str> + fmt::Debug,
+    {
+        self.rescore("rescore_session", "sessions", session_id.as_ref(), opts)
+            .await
+    }
+
+    /// The score url for an entity, such as `users/{id}/score`, honoring any path overrides.
+    #[cfg(feature = "score")]
+    fn score_url(
+        &self,
+        entity: &'static str,
+        id: &str,
+        suffix: &'static str,
+        opts: &ScoreOptions,
+    ) -> String {
+        format!(
             "{}/{}/{}/{}/{}",
-            self.origin, version, path_prefix, user_id, path_suffix
-        );
+            self.origin,
+            opts.version.unwrap_or_default(),
+            opts.path_prefix.unwrap_or(entity),
+            urlencoding::encode(id),
+            opts.path_suffix.unwrap_or(suffix)
+        )
+    }
+
+    #[cfg(feature = "score")]
+    async fn fetch_score(
+        &self,
+        endpoint: &'static str,
+        entity: &'static str,
+        id: &str,
+        mut opts: ScoreOptions,
+    ) -> Result<ScoreResponse> {
+        if opts.abuse_types.is_none() {
+            opts.abuse_types = self.default_abuse_types.clone();
+        }
+
+        let headers = opts.extra_headers.take().unwrap_or_default();
+        let timeout = opts.timeout.unwrap_or(self.default_timeout);
+        let url = self.score_url(entity, id, "score", &opts);
         opts.api_key.get_or_insert_with(|| self.api_key.clone());
 
         let query_params = ScoreQueryParams::from(opts);
-        debug!(?url, query_params = ?serde_urlencoded::to_string(&query_params), "retrieving score");
+        debug!(?url, abuse_types = ?query_params.abuse_types, "retrieving score");
 
+        let query_params = query_params.into();
         let score_json = self
-            .http_client
-            .get(&url, &query_params.into(), timeout, None)
+            .with_retries(endpoint, || {
+                self.http_client
+                    .get(&url, &query_params, timeout, None, &headers)
+            })
             .await?;
 
         trace!(?score_json, "sift score API response");
@@ -194,34 +708,32 @@ impl<T: HttpClient> Client<T> {
         Ok(score_response)
     }
 
-    /// Rescores the specified user for the specified abuse types and returns the resulting
-    /// score(s).
-    ///
-    /// See <https://sift.com/developers/docs/curl/score-api/rescore>
     #[cfg(feature = "score")]
-    #[instrument(skip(self, opts))]
-    pub async fn rescore_user<U>(&self, user_id: U, mut opts: ScoreOptions) -> Result<ScoreResponse>
-    where
-        U: AsRef<str> + fmt::Debug,
-    {
-        let version = opts.version.unwrap_or(events::ApiVersion::V205);
-        let path_prefix = opts.path_prefix.unwrap_or("users");
-        let path_suffix = opts.path_prefix.unwrap_or("score");
-        let timeout = opts.timeout.unwrap_or(DEFAULT_TIMEOUT);
-        let user_id = urlencoding::encode(user_id.as_ref()).to_string();
+    async fn rescore(
+        &self,
+        endpoint: &'static str,
+        entity: &'static str,
+        id: &str,
+        mut opts: ScoreOptions,
+    ) -> Result<ScoreResponse> {
+        if opts.abuse_types.is_none() {
+            opts.abuse_types = self.default_abuse_types.clone();
+        }
 
-        let url = format!(
-            "{}/{}/{}/{}/{}",
-            self.origin, version, path_prefix, user_id, path_suffix
-        );
+        let headers = opts.extra_headers.take().unwrap_or_default();
+        let timeout = opts.timeout.unwrap_or(self.default_timeout);
+        let url = self.score_url(entity, id, "score", &opts);
         opts.api_key.get_or_insert_with(|| self.api_key.clone());
 
         let query_params = ScoreQueryParams::from(opts);
-        debug!(?url, query_params = ?serde_urlencoded::to_string(&query_params), "rescoring");
+        debug!(?url, abuse_types = ?query_params.abuse_types, "rescoring");
 
+        let query_params = query_params.into();
         let score_json = self
-            .http_client
-            .post(&url, Some(&query_params.into()), None, timeout, None)
+            .with_retries(endpoint, || {
+                self.http_client
+                    .post(&url, Some(&query_params), None, timeout, None, &headers)
+            })
             .await?;
 
         trace!(?score_json, "sift score API response");
@@ -246,7 +758,11 @@ impl<T: HttpClient> Client<T> {
     ///
     /// See <https://sift.com/developers/docs/curl/labels-api/label-user>
     #[cfg(feature = "labels")]
-    #[instrument(skip(self, properties, opts))]
+    #[instrument(
+        skip(self, user_id, properties, opts),
+        fields(endpoint = "label", user = hash_user_id(user_id.as_ref()))
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn label<U>(
         &self,
         user_id: U,
@@ -263,6 +779,128 @@ impl<T: HttpClient> Client<T> {
         Ok(())
     }
 
+    /// Labels many users, sending up to `concurrency` labels at once.
+    ///
+    /// Returns the result of each label in the order given, so partial failures can be retried.
+    #[cfg(feature = "labels")]
+    #[instrument(
+        skip(self, items, opts),
+        fields(endpoint = "label_many", count = items.len(), concurrency = opts.concurrency)
+    )]
+    #[must_use = "the labels are only sent when awaited"]
+    pub async fn label_many(
+        &self,
+        items: Vec<(String, LabelProperties)>,
+        opts: LabelManyOptions,
+    ) -> Vec<Result<()>> {
+        use futures::stream::{self, StreamExt};
+
+        let LabelManyOptions {
+            concurrency,
+            label_options,
+        } = opts;
+
+        let mut results: Vec<_> = stream::iter(items.into_iter().enumerate())
+            .map(|(i, (user_id, properties))| {
+                let label_options = label_options.clone();
+                async move { (i, self.label(user_id, properties, label_options).await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Removes the label previously applied to a user for an abuse type.
+    ///
+    /// Removing a label that was never applied succeeds.
+    ///
+    /// See <https://sift.com/developers/docs/curl/labels-api/unlabel-user>
+    #[cfg(feature = "labels")]
+    #[instrument(
+        skip(self, user_id, opts),
+        fields(endpoint = "unlabel_user", user = hash_user_id(user_id.as_ref()))
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn unlabel_user<U>(
+        &self,
+        user_id: U,
+        abuse_type: AbuseType,
+        opts: LabelOptions,
+    ) -> Result<()>
+    where
+        U: AsRef<str> + fmt::Debug,
+    {
+        let LabelOptions {
+            timeout,
+            api_key,
+            version,
+            extra_headers,
+        } = opts;
+
+        let headers = extra_headers.unwrap_or_default();
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let version = version.unwrap_or_default();
+        let api_key = api_key.as_deref().unwrap_or(&self.api_key);
+        let user_id = urlencoding::encode(user_id.as_ref()).to_string();
+
+        let path = format!("{}/{}/users/{}/labels", self.origin, version, user_id);
+        debug!(url = ?path, %abuse_type, "unlabeling user");
+
+        let query = serde_urlencoded::to_string([
+            ("abuse_type", abuse_type.as_str()),
+            ("api_key", api_key),
+        ])
+        .map_err(|err| Error::Validation(err.to_string()))?;
+        let url = format!("{}?{}", path, query);
+
+        self.with_retries("unlabel_user", || {
+            self.http_client.delete(&url, timeout, api_key, &headers)
+        })
+        .await
+    }
+
+    /// Labels a user, or applies the equivalent decision.
+    ///
+    /// Eases migrating from the Labels API to Decisions: with [LabelRoute::Label] this behaves
+    /// like [Client::label], and with [LabelRoute::Decision] the label is mapped to a decision id
+    /// and applied to the user with [Client::apply_decision].
+    ///
+    /// # Errors
+    ///
+    /// When routed to decisions, this errors if an `account_id` is not set for this client.
+    #[cfg(all(feature = "labels", feature = "decisions"))]
+    #[instrument(
+        skip(self, user_id, properties, route, opts),
+        fields(endpoint = "label_or_decide", user = hash_user_id(user_id.as_ref()))
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn label_or_decide<U>(
+        &self,
+        user_id: U,
+        properties: LabelProperties,
+        route: LabelRoute,
+        opts: LabelOptions,
+    ) -> Result<()>
+    where
+        U: AsRef<str> + fmt::Debug,
+    {
+        match route {
+            LabelRoute::Label => self.label(user_id, properties, opts).await,
+            LabelRoute::Decision(decision_id) => {
+                let entity = Entity::User {
+                    user_id: user_id.as_ref().into(),
+                };
+                self.apply_decision(entity, properties.into_decision(decision_id))
+                    .await?;
+
+                Ok(())
+            }
+        }
+    }
+
     /// Send a OTP to an end user.
     ///
     /// Sift **strongly** recommends using Verification with Workflows. However, you may want to use
@@ -271,22 +909,65 @@ impl<T: HttpClient> Client<T> {
     /// code to the user. It will also produce a pending `Verification` event in the user's activity
     /// log.
     ///
+    /// The event is checked with [SendRequestEvent::validate] before it is sent. Sending is never
+    /// retried by the [RetryPolicy], since a retry could send the user a second code.
+    ///
+    /// [SendRequestEvent::validate]: crate::verification::SendRequestEvent::validate
+    ///
     /// <https://sift.com/developers/docs/curl/verification-api/send>
     #[cfg(feature = "verification")]
-    #[instrument(skip(self, req))]
+    #[instrument(
+        skip(self, req),
+        fields(endpoint = "send_verification", user = hash_user_id(&req.user_id))
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn send_verification(&self, req: SendRequest) -> Result<SendResponse> {
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = verification::ApiVersion::V1;
+        self.send_verification_with_options(req, SendOptions::default())
+            .await
+    }
+
+    /// Send a OTP to an end user, overriding the timeout, API version or headers for this call.
+    ///
+    /// See [Client::send_verification].
+    #[cfg(feature = "verification")]
+    #[instrument(
+        skip(self, req, opts),
+        fields(
+            endpoint = "send_verification",
+            api_version = %opts.version.unwrap_or_default(),
+            user = hash_user_id(&req.user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn send_verification_with_options(
+        &self,
+        req: SendRequest,
+        opts: SendOptions,
+    ) -> Result<SendResponse> {
+        req.event.validate()?;
+
+        let SendOptions {
+            timeout,
+            version,
+            extra_headers,
+        } = opts;
+
+        let headers = extra_headers.unwrap_or_default();
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let api_version = version.unwrap_or_default();
         let url = format!("{}/{}/verification/send", self.origin, api_version);
-        let body = serde_json::json!(req);
+        let body = to_json(&req)?;
         let auth = Some(self.api_key.as_str());
 
         debug!(?url, ?req, "sending verification");
-        trace!(body = ?serde_json::to_string(&body), "verification data");
+        trace!(body = ?self.redacted(&body), "verification data");
 
         let response_json = self
-            .http_client
-            .post(&url, None, Some(&body), timeout, auth)
+            .measured(
+                "send_verification",
+                self.http_client
+                    .post(&url, None, Some(&body), timeout, auth, &headers),
+            )
             .await?;
 
         trace!(?response_json, "sift verification API response");
@@ -300,7 +981,7 @@ impl<T: HttpClient> Client<T> {
                 } if status != 0 => {
                     tracing::warn!(status, ?error_message, "verification send error");
                     Err(Error::Request {
-                        status,
+                        status: status.into(),
                         error_message,
                     })
                 }
@@ -323,20 +1004,56 @@ impl<T: HttpClient> Client<T> {
     ///
     /// <https://sift.com/developers/docs/curl/verification-api/resend>
     #[cfg(feature = "verification")]
-    #[instrument(skip(self, req))]
+    #[instrument(
+        skip(self, req),
+        fields(endpoint = "resend_verification", user = hash_user_id(&req.user_id))
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn resend_verification(&self, req: ResendRequest) -> Result<SendResponse> {
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = verification::ApiVersion::V1;
+        self.resend_verification_with_options(req, ResendOptions::default())
+            .await
+    }
+
+    /// Re-send a OTP to an end user, overriding the timeout, API version or headers for this call.
+    ///
+    /// See [Client::resend_verification].
+    #[cfg(feature = "verification")]
+    #[instrument(
+        skip(self, req, opts),
+        fields(
+            endpoint = "resend_verification",
+            api_version = %opts.version.unwrap_or_default(),
+            user = hash_user_id(&req.user_id),
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn resend_verification_with_options(
+        &self,
+        req: ResendRequest,
+        opts: ResendOptions,
+    ) -> Result<SendResponse> {
+        let ResendOptions {
+            timeout,
+            version,
+            extra_headers,
+        } = opts;
+
+        let headers = extra_headers.unwrap_or_default();
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let api_version = version.unwrap_or_default();
         let url = format!("{}/{}/verification/resend", self.origin, api_version);
-        let body = serde_json::json!(req);
+        let body = to_json(&req)?;
         let auth = Some(self.api_key.as_str());
 
         debug!(?url, ?req, "resending verification");
-        trace!(body = ?serde_json::to_string(&body), "verification data");
+        trace!(body = ?self.redacted(&body), "verification data");
 
         let response_json = self
-            .http_client
-            .post(&url, None, Some(&body), timeout, auth)
+            .measured(
+                "resend_verification",
+                self.http_client
+                    .post(&url, None, Some(&body), timeout, auth, &headers),
+            )
             .await?;
 
         trace!(?response_json, "sift verification API response");
@@ -350,7 +1067,7 @@ impl<T: HttpClient> Client<T> {
                 } if status != 0 => {
                     tracing::warn!(status, ?error_message, "verification resend error");
                     Err(Error::Request {
-                        status,
+                        status: status.into(),
                         error_message,
                     })
                 }
@@ -377,9 +1094,20 @@ impl<T: HttpClient> Client<T> {
     ///   message to the user. The message should inform the user what to do next ("click resend
     ///   and try again" or "wait for minutes and try again")
     ///
+    /// Checks are never retried by the [RetryPolicy], as each one counts as an attempt against
+    /// the code.
+    ///
     /// See <https://sift.com/developers/docs/curl/verification-api/check>
     #[cfg(feature = "verification")]
-    #[instrument(skip(self, code, opts))]
+    #[instrument(
+        skip(self, user_id, code, opts),
+        fields(
+            endpoint = "check_verification",
+            api_version = %opts.version.unwrap_or_default(),
+            user = tracing::field::Empty,
+        )
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn check_verification<U>(
         &self,
         user_id: U,
@@ -394,25 +1122,31 @@ impl<T: HttpClient> Client<T> {
             verified_entity_id,
             timeout,
             version,
+            extra_headers,
         } = opts;
 
+        let headers = extra_headers.unwrap_or_default();
         let req = CheckRequest {
             user_id: user_id.into(),
             code,
             verified_event,
             verified_entity_id,
         };
-        let timeout = timeout.unwrap_or(DEFAULT_TIMEOUT);
-        let api_version = version.unwrap_or(verification::ApiVersion::V1);
+        tracing::Span::current().record("user", hash_user_id(&req.user_id));
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let api_version = version.unwrap_or_default();
         let url = format!("{}/{}/verification/check", self.origin, api_version);
-        let body = serde_json::json!(req);
+        let body = to_json(&req)?;
         let auth = Some(self.api_key.as_str());
 
         debug!(?url, ?req, "checking verification");
 
         let response_json = self
-            .http_client
-            .post(&url, None, Some(&body), timeout, auth)
+            .measured(
+                "check_verification",
+                self.http_client
+                    .post(&url, None, Some(&body), timeout, auth, &headers),
+            )
             .await?;
 
         trace!(?response_json, "sift verification API response");
@@ -426,7 +1160,7 @@ impl<T: HttpClient> Client<T> {
                 } if status != 0 => {
                     tracing::warn!(status, ?error_message, "verification check error");
                     Err(Error::Request {
-                        status,
+                        status: status.into(),
                         error_message,
                     })
                 }
@@ -441,36 +1175,86 @@ impl<T: HttpClient> Client<T> {
         }
     }
 
-    /// Creates a new webhook with a specified URL.
+    /// Record the outcome of a verification.
     ///
-    /// See <https://sift.com/developers/docs/curl/webhooks-api/create> for examples.
+    /// Tracks the final [Event::Verification] for a verification flow, closing the loop Sift
+    /// expects: send, check, then record. Typically called with [VerificationStatus::Success] once
+    /// [Client::check_verification] succeeds.
     ///
-    /// # Errors
+    /// `verified_entity_id` is the id of the entity impacted by the verified event, e.g. the
+    /// session id for a login or the order id for an order. See
+    /// [VerificationProperties::verified_entity_id].
+    ///
+    /// See <https://sift.com/developers/docs/curl/events-api/reserved-events/verification>
+    #[cfg(feature = "verification")]
+    #[instrument(
+        skip(self, user_id, session_id, verified_entity_id),
+        fields(endpoint = "complete_verification", user = tracing::field::Empty)
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn complete_verification<U, S>(
+        &self,
+        user_id: U,
+        session_id: S,
+        verified_event: VerifiedEvent,
+        verified_entity_id: Option<String>,
+        status: VerificationStatus,
+    ) -> Result<()>
+    where
+        U: Into<String> + fmt::Debug,
+        S: Into<String> + fmt::Debug,
+    {
+        let event = Event::Verification {
+            user_id: user_id.into(),
+            session_id: session_id.into(),
+            status,
+            properties: VerificationProperties {
+                verified_event: Some(verified_event),
+                verified_entity_id,
+                ..Default::default()
+            },
+        };
+        tracing::Span::current().record("user", event.user_id().map(hash_user_id));
+
+        self.track(event, EventOptions::default()).await?;
+
+        Ok(())
+    }
+
+    /// Creates a new webhook with a specified URL.
+    ///
+    /// See <https://sift.com/developers/docs/curl/webhooks-api/create> for examples.
+    ///
+    /// Creating a webhook is never retried by the [RetryPolicy], since a retry after a failed
+    /// attempt that reached Sift would create a duplicate webhook.
+    ///
+    /// # Errors
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "webhooks")]
-    #[instrument(skip(self, req))]
+    #[instrument(skip(self, req), fields(endpoint = "create_webhook"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn create_webhook(&self, req: WebhookRequest) -> Result<Webhook> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = webhooks::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = webhooks::ApiVersion::default();
         let url = format!(
             "{}/{}/accounts/{}/webhooks",
             self.origin, api_version, account_id
         );
-        let body = serde_json::json!(req);
+        let body = to_json(&req)?;
         let auth = Some(self.api_key.as_str());
 
         debug!(?url, ?req, "creating webhook");
-        trace!(body = ?serde_json::to_string(&body), "webhook data");
+        trace!(body = ?self.redacted(&body), "webhook data");
 
         let response_json = self
-            .http_client
-            .post(&url, None, Some(&body), timeout, auth)
+            .measured(
+                "create_webhook",
+                self.http_client
+                    .post(&url, None, Some(&body), timeout, auth, &[]),
+            )
             .await?;
 
         trace!(?response_json, "sift webhook API response");
@@ -491,15 +1275,13 @@ impl<T: HttpClient> Client<T> {
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "webhooks")]
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(endpoint = "get_webhooks"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn get_webhooks(&self) -> Result<Vec<Webhook>> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = webhooks::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = webhooks::ApiVersion::default();
         let url = format!(
             "{}/{}/accounts/{}/webhooks",
             self.origin, api_version, account_id,
@@ -508,9 +1290,12 @@ impl<T: HttpClient> Client<T> {
 
         debug!(?url, "Retrieving webhooks");
 
+        let query_params = QueryParams::default();
         let response_json = self
-            .http_client
-            .get(&url, &QueryParams::default(), timeout, auth)
+            .with_retries("get_webhooks", || {
+                self.http_client
+                    .get(&url, &query_params, timeout, auth, &[])
+            })
             .await?;
 
         trace!(body = ?serde_json::to_string(&response_json), "sift webhook API response");
@@ -529,15 +1314,13 @@ impl<T: HttpClient> Client<T> {
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "webhooks")]
-    #[instrument(skip(self, id))]
+    #[instrument(skip(self, id), fields(endpoint = "get_webhook"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn get_webhook(&self, id: u64) -> Result<Webhook> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = webhooks::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = webhooks::ApiVersion::default();
         let url = format!(
             "{}/{}/accounts/{}/webhooks/{}",
             self.origin, api_version, account_id, id
@@ -546,9 +1329,12 @@ impl<T: HttpClient> Client<T> {
 
         debug!(?url, "Retrieving webhook");
 
+        let query_params = QueryParams::default();
         let response_json = self
-            .http_client
-            .get(&url, &QueryParams::default(), timeout, auth)
+            .with_retries("get_webhook", || {
+                self.http_client
+                    .get(&url, &query_params, timeout, auth, &[])
+            })
             .await?;
 
         trace!(?response_json, "sift webhook API response");
@@ -567,26 +1353,28 @@ impl<T: HttpClient> Client<T> {
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "webhooks")]
-    #[instrument(skip(self, webhook))]
+    #[instrument(skip(self, webhook), fields(endpoint = "update_webhook"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn update_webhook(&self, webhook: Webhook) -> Result<Webhook> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = webhooks::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = webhooks::ApiVersion::default();
         let url = format!(
             "{}/{}/accounts/{}/webhooks/{}",
             self.origin, api_version, account_id, webhook.id,
         );
-        let body = serde_json::json!(webhook);
+        let body = to_json(&webhook)?;
         let auth = self.api_key.as_str();
 
         debug!(?url, "updating webhook");
-        trace!(body = ?serde_json::to_string(&body), "webhook data");
+        trace!(body = ?self.redacted(&body), "webhook data");
 
-        let response_json = self.http_client.put(&url, &body, timeout, auth).await?;
+        let response_json = self
+            .with_retries("update_webhook", || {
+                self.http_client.put(&url, &body, timeout, auth, &[])
+            })
+            .await?;
 
         trace!(?response_json, "sift webhook update response");
 
@@ -600,19 +1388,19 @@ impl<T: HttpClient> Client<T> {
     ///
     /// See <https://sift.com/developers/docs/curl/webhooks-api/delete> for examples.
     ///
+    /// Deleting a webhook that doesn't exist succeeds, so teardown can be retried safely.
+    ///
     /// # Errors
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "webhooks")]
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(endpoint = "delete_webhook"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn delete_webhook(&self, id: u64) -> Result<()> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = webhooks::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = webhooks::ApiVersion::default();
         let url = format!(
             "{}/{}/accounts/{}/webhooks/{}",
             self.origin, api_version, account_id, id,
@@ -621,39 +1409,39 @@ impl<T: HttpClient> Client<T> {
 
         debug!(?url, "deleting webhook");
 
-        self.http_client.delete(&url, timeout, auth).await
+        self.with_retries("delete_webhook", || {
+            self.http_client.delete(&url, timeout, auth, &[])
+        })
+        .await
     }
 
     /// Verify webhook signature.
     ///
     /// Used to verify that webhook invocations originate from Sift's servers.
+    #[cfg(feature = "webhooks")]
     pub fn verify_webhook_signature(
         &self,
         signature: &str,
         body: &[u8],
         webhook_secret: &str,
     ) -> Result<()> {
-        use hmac::{Hmac, Mac};
-
-        match signature.split_once('=') {
-            Some(("sha1", tag)) if tag.is_ascii() && tag.len() == 40 => {
-                let mut mac = Hmac::<sha1::Sha1>::new_from_slice(webhook_secret.as_bytes())
-                    .map_err(|err| Error::Server(err.to_string()))?;
-
-                mac.update(body);
-
-                // parse hex string
-                let hex = (0..20).fold([0; 20], |mut acc, i| {
-                    acc[i] = u8::from_str_radix(&tag[(i * 2)..=(i * 2 + 1)], 16).unwrap_or(0);
-                    acc
-                });
+        webhooks::check_signature(webhook_secret, body, signature)
+    }
 
-                mac.verify_slice(&hex)
-                    .map_err(|err| Error::Server(err.to_string()))
-            }
-            Some((alg, _)) => Err(Error::Server(format!("unsupported type: {}", alg))),
-            None => Err(Error::Server("Invalid signature value".into())),
-        }
+    /// Verify a webhook signature, then parse the webhook payload.
+    ///
+    /// See [webhooks::handle].
+    #[cfg(feature = "webhooks")]
+    pub fn verify_webhook_and_parse<H>(
+        &self,
+        headers: &H,
+        body: &[u8],
+        webhook_secret: &str,
+    ) -> Result<webhooks::WebhookPayload>
+    where
+        H: webhooks::WebhookHeaders + ?Sized,
+    {
+        webhooks::handle(webhook_secret, headers, body)
     }
 
     /// Apply a decision
@@ -668,38 +1456,43 @@ impl<T: HttpClient> Client<T> {
     /// that same Decision in the Sift Console, we would send a webhook to your application so that
     /// you can update your system.
     ///
+    /// Applying a decision is never retried by the [RetryPolicy]: if a failed attempt reached
+    /// Sift, a retry would record the decision twice.
+    ///
     /// [Decisions section]: https://sift.com/console/decisions
     ///
     /// # Errors
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "decisions")]
-    #[instrument(skip(self, entity, decision))]
+    #[instrument(skip(self, entity, decision), fields(endpoint = "apply_decision"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn apply_decision(
         &self,
         entity: Entity,
         decision: DecisionRequest,
     ) -> Result<Decision> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        decision.validate()?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = decisions::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = decisions::ApiVersion::default();
         let url = format!(
             "{}/{}/accounts/{}/{}/decisions",
             self.origin, api_version, account_id, entity,
         );
-        let body = serde_json::json!(&decision);
+        let body = to_json(&decision)?;
         let auth = Some(self.api_key.as_str());
 
         debug!(?url, ?decision, "applying decision");
-        trace!(body = ?serde_json::to_string(&body), "decision data");
+        trace!(body = ?self.redacted(&body), "decision data");
 
         let response_json = self
-            .http_client
-            .post(&url, None, Some(&body), timeout, auth)
+            .measured(
+                "apply_decision",
+                self.http_client
+                    .post(&url, None, Some(&body), timeout, auth, &[]),
+            )
             .await?;
 
         trace!(?response_json, "decision response");
@@ -724,15 +1517,13 @@ impl<T: HttpClient> Client<T> {
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "decisions")]
-    #[instrument(skip(self, entity))]
+    #[instrument(skip(self, entity), fields(endpoint = "decision_status"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn decision_status(&self, entity: Entity) -> Result<Decisions> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        let account_id = self.resolved_account_id()?;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = decisions::ApiVersion::V3;
+        let timeout = self.default_timeout;
+        let api_version = decisions::ApiVersion::default();
 
         // The path for orders for this api is different than the others in that it does not have a
         // users/{userId} prefix
@@ -752,9 +1543,12 @@ impl<T: HttpClient> Client<T> {
 
         debug!(?url, "getting decision status");
 
+        let query_params = QueryParams::default();
         let response_json = self
-            .http_client
-            .get(&url, &QueryParams::default(), timeout, auth)
+            .with_retries("decision_status", || {
+                self.http_client
+                    .get(&url, &query_params, timeout, auth, &[])
+            })
             .await?;
 
         trace!(?response_json, "decision status response");
@@ -771,7 +1565,11 @@ impl<T: HttpClient> Client<T> {
     ///
     /// This errors if an `account_id` is not set for this client.
     #[cfg(feature = "decisions")]
-    #[instrument(skip(self, entity_type, abuse_types, limit, offset))]
+    #[instrument(
+        skip(self, entity_type, abuse_types, limit, offset),
+        fields(endpoint = "get_decisions")
+    )]
+    #[must_use = "the request is only sent when awaited, and may fail"]
     pub async fn get_decisions(
         &self,
         entity_type: Option<EntityType>,
@@ -779,19 +1577,50 @@ impl<T: HttpClient> Client<T> {
         limit: Option<u32>,
         offset: Option<u32>,
     ) -> Result<DecisionPage> {
-        let account_id = self
-            .account_id
-            .as_ref()
-            .ok_or_else(|| Error::Server("account id not specified".into()))?;
+        self.list_decisions(ListDecisionsOptions {
+            entity_type,
+            abuse_types,
+            from: offset,
+            limit,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// List the decisions configured for your account.
+    ///
+    /// Returns a single page of decisions, use [Client::list_all_decisions] to retrieve every
+    /// page.
+    ///
+    /// See <https://sift.com/developers/docs/curl/decisions-api/apply-decisions/get-decisions>
+    ///
+    /// # Errors
+    ///
+    /// This errors if an `account_id` is not set for this client.
+    #[cfg(feature = "decisions")]
+    #[instrument(skip(self, opts), fields(endpoint = "list_decisions"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn list_decisions(&self, opts: ListDecisionsOptions) -> Result<DecisionPage> {
+        let account_id = self.resolved_account_id()?;
+
+        let ListDecisionsOptions {
+            entity_type,
+            abuse_types,
+            from,
+            limit,
+            timeout,
+            extra_headers,
+        } = opts;
 
-        let timeout = DEFAULT_TIMEOUT;
-        let api_version = decisions::ApiVersion::V3;
+        let headers = extra_headers.unwrap_or_default();
+        let timeout = timeout.unwrap_or(self.default_timeout);
+        let api_version = decisions::ApiVersion::default();
 
         let query_params = QueryParams {
             entity_type,
             abuse_types,
             limit,
-            from: offset,
+            from,
             ..Default::default()
         };
         let url = format!(
@@ -807,8 +1636,10 @@ impl<T: HttpClient> Client<T> {
         );
 
         let response_json = self
-            .http_client
-            .get(&url, &QueryParams::default(), timeout, auth)
+            .with_retries("list_decisions", || {
+                self.http_client
+                    .get(&url, &query_params, timeout, auth, &headers)
+            })
             .await?;
 
         trace!(
@@ -821,17 +1652,97 @@ impl<T: HttpClient> Client<T> {
             DecisionResult::Error(err) => Err(err),
         }
     }
+
+    /// List every decision configured for your account.
+    ///
+    /// Follows the `from` offset of each [DecisionPage] until there are no more results, starting
+    /// at `opts.from` if set.
+    ///
+    /// # Errors
+    ///
+    /// This errors if an `account_id` is not set for this client.
+    #[cfg(feature = "decisions")]
+    #[instrument(skip(self, opts), fields(endpoint = "list_all_decisions"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn list_all_decisions(
+        &self,
+        opts: ListDecisionsOptions,
+    ) -> Result<Vec<DecisionData>> {
+        self.decisions_stream(opts).try_collect().await
+    }
+
+    /// Stream every decision configured for your account, fetching pages as they are needed.
+    ///
+    /// Follows the `from` offset of each [DecisionPage] until there are no more results, starting
+    /// at `opts.from` if set. A failed page ends the stream with its error.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error if an `account_id` is not set for this client.
+    #[cfg(feature = "decisions")]
+    pub fn decisions_stream(
+        &self,
+        opts: ListDecisionsOptions,
+    ) -> impl Stream<Item = Result<DecisionData>> + '_ {
+        let from = opts.from.unwrap_or(0);
+
+        Paginator::new(from, move |from| {
+            self.list_decisions(ListDecisionsOptions {
+                from: Some(from),
+                ..opts.clone()
+            })
+        })
+        .into_stream()
+    }
+
+    /// Look up a workflow run by its id.
+    ///
+    /// Run ids are returned in the workflow statuses of tracked events, and in decision webhooks
+    /// applied by workflows.
+    ///
+    /// # Errors
+    ///
+    /// This errors if an `account_id` is not set for this client.
+    #[cfg(feature = "workflows")]
+    #[instrument(skip(self), fields(endpoint = "get_workflow_run"))]
+    #[must_use = "the request is only sent when awaited, and may fail"]
+    pub async fn get_workflow_run(&self, run_id: &str) -> Result<WorkflowRun> {
+        let account_id = self.resolved_account_id()?;
+
+        let timeout = self.default_timeout;
+        let api_version = workflows::ApiVersion::default();
+        let url = format!(
+            "{}/{}/accounts/{}/workflows/runs/{}",
+            self.origin,
+            api_version,
+            account_id,
+            urlencoding::encode(run_id),
+        );
+        let auth = Some(self.api_key.as_str());
+
+        debug!(?url, "getting workflow run");
+
+        let query_params = QueryParams::default();
+        let response_json = self
+            .with_retries("get_workflow_run", || {
+                self.http_client
+                    .get(&url, &query_params, timeout, auth, &[])
+            })
+            .await?;
+
+        trace!(?response_json, "workflow run response");
+
+        match serde_json::from_value(response_json)? {
+            WorkflowRunResult::Run(run) => Ok(run),
+            WorkflowRunResult::Error(err) => Err(err),
+        }
+    }
 }
 
 impl<T: HttpClient + Default> Client<T> {
     /// construct a new client with a given api key and default HTTP client
     pub fn with_api_key(api_key: impl Into<String>) -> Self {
-        Client {
-            api_key: api_key.into(),
-            account_id: None,
-            http_client: Default::default(),
-            origin: SIFT_ORIGIN.into(),
-        }
+        Client::new(api_key, Default::default())
     }
 }
 
@@ -841,6 +1752,180 @@ impl<T> fmt::Debug for Client<T> {
             .field("api_key", &"****")
             .field("account_id", &self.account_id)
             .field("origin", &self.origin)
+            .field("retry_policy", &self.retry_policy)
+            .field("default_timeout", &self.default_timeout)
+            .field("default_abuse_types", &self.default_abuse_types)
+            .field("redact_pii", &self.redact_pii)
+            .field("metrics", &self.metrics.is_some())
+            .field("compress_requests", &self.compress_requests)
+            .field("compression_threshold", &self.compression_threshold)
+            .finish()
+    }
+}
+
+/// Connect and total timeouts for calls to Sift.
+///
+/// `total` bounds a whole call, from connecting to reading the response, and is applied by every
+/// HTTP client. `connect` only bounds establishing a connection, which HTTP clients configure when
+/// they are built rather than per call, so it is only applied to `reqwest` clients built with
+/// `Timeouts::configure` or `ReqwestClient::with_timeouts`. Other HTTP clients use the total
+/// timeout alone.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Timeouts {
+    /// The timeout for establishing a connection, if supported by the HTTP client.
+    pub connect: Option<Duration>,
+
+    /// The timeout for the whole call.
+    pub total: Duration,
+}
+
+impl Timeouts {
+    /// A total timeout, without a separate connect timeout.
+    pub fn new(total: Duration) -> Self {
+        Timeouts {
+            connect: None,
+            total,
+        }
+    }
+
+    /// Set the timeout for establishing a connection.
+    #[must_use]
+    pub fn with_connect(mut self, connect: Duration) -> Self {
+        self.connect = Some(connect);
+        self
+    }
+
+    /// Apply these timeouts to a `reqwest` client builder.
+    ///
+    /// ```
+    /// use sift_science::Timeouts;
+    /// use std::time::Duration;
+    ///
+    /// let timeouts = Timeouts::new(Duration::from_secs(10)).with_connect(Duration::from_millis(250));
+    /// let builder = timeouts.configure(reqwest::Client::builder());
+    ///
+    /// let config = format!("{:?}", builder);
+    /// assert!(config.contains("connect_timeout: 250ms"));
+    /// assert!(config.contains("timeout: 10s"));
+    /// ```
+    #[cfg(feature = "reqwest")]
+    pub fn configure(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        let builder = builder.timeout(self.total);
+
+        match self.connect {
+            Some(connect) => builder.connect_timeout(connect),
+            None => builder,
+        }
+    }
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Timeouts::new(DEFAULT_TIMEOUT)
+    }
+}
+
+/// Builder for a [Client].
+pub struct ClientBuilder<T> {
+    client: Client<T>,
+}
+
+impl<T: HttpClient> ClientBuilder<T> {
+    /// Set the sift api key.
+    #[must_use]
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.client.api_key = api_key.into();
+        self
+    }
+
+    /// Set the sift account id.
+    #[must_use]
+    pub fn account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.client.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Set the sift api origin.
+    #[must_use]
+    pub fn origin(mut self, origin: impl Into<String>) -> Self {
+        self.client.origin = origin.into();
+        self
+    }
+
+    /// Set the timeout used when a call does not override it.
+    ///
+    /// Defaults to 2 seconds.
+    #[must_use]
+    pub fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.client.default_timeout = timeout;
+        self
+    }
+
+    /// Set the abuse types used for scoring when a call does not specify any.
+    ///
+    /// Abuse types set on a call's [EventOptions] or [ScoreOptions] always take precedence.
+    #[must_use]
+    pub fn abuse_types(mut self, abuse_types: Vec<AbuseType>) -> Self {
+        self.client.default_abuse_types = Some(abuse_types);
+        self
+    }
+
+    /// Automatically retry requests that fail with transient errors.
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Mask PII in logged request bodies. See [Client::redact_pii].
+    ///
+    /// Defaults to `true`.
+    #[must_use]
+    pub fn redact_pii(mut self, redact_pii: bool) -> Self {
+        self.client.redact_pii = redact_pii;
+        self
+    }
+
+    /// Gzip event bodies larger than the [compression threshold], when the HTTP client
+    /// [supports gzip].
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [compression threshold]: ClientBuilder::compression_threshold
+    /// [supports gzip]: HttpClient::supports_gzip
+    #[must_use]
+    pub fn compress_requests(mut self, compress_requests: bool) -> Self {
+        self.client.compress_requests = compress_requests;
+        self
+    }
+
+    /// Size in bytes above which event bodies are compressed.
+    ///
+    /// Defaults to 1 KiB.
+    #[must_use]
+    pub fn compression_threshold(mut self, compression_threshold: usize) -> Self {
+        self.client.compression_threshold = compression_threshold;
+        self
+    }
+
+    /// Report every API call to a [Metrics] hook. See [Client::with_metrics].
+    #[must_use]
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.client.metrics = Some(metrics);
+        self
+    }
+
+    /// Build the [Client].
+    #[must_use]
+    pub fn build(self) -> Client<T> {
+        self.client
+    }
+}
+
+impl<T> fmt::Debug for ClientBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("client", &self.client)
             .finish()
     }
 }
@@ -878,6 +1963,9 @@ pub struct QueryParams {
     /// See <https://siftscience.com/developers/docs/ruby/workflows-api/workflow-decisions>
     return_workflow_status: Option<bool>,
 
+    /// If true, requests that workflow statuses include the route taken through each workflow.
+    return_route_info: Option<bool>,
+
     /// Limit APIs that support pagination.
     limit: Option<u32>,
 
@@ -885,6 +1973,27 @@ pub struct QueryParams {
     from: Option<u32>,
 }
 
+impl QueryParams {
+    /// Serialize these query params as they are sent to the Sift API.
+    pub fn to_query_string(&self) -> Result<String> {
+        serde_urlencoded::to_string(self).map_err(|err| Error::Server(err.to_string()))
+    }
+}
+
+#[cfg(feature = "test-util")]
+impl From<EventOptions> for QueryParams {
+    fn from(options: EventOptions) -> Self {
+        EventQueryParams::from(options).into()
+    }
+}
+
+#[cfg(all(feature = "test-util", feature = "score"))]
+impl From<ScoreOptions> for QueryParams {
+    fn from(options: ScoreOptions) -> Self {
+        ScoreQueryParams::from(options).into()
+    }
+}
+
 impl From<EventQueryParams> for QueryParams {
     fn from(eqp: EventQueryParams) -> Self {
         let EventQueryParams {
@@ -910,103 +2019,495 @@ impl From<ScoreQueryParams> for QueryParams {
         let ScoreQueryParams {
             api_key,
             abuse_types,
+            return_workflow_status,
+            return_route_info,
         } = sqp;
 
         QueryParams {
             api_key: Some(api_key),
             abuse_types,
+            return_workflow_status,
+            return_route_info,
             ..Default::default()
         }
     }
 }
 
+// Serialize a request body, failing rather than panicking on values that can't be represented,
+// e.g. times before the unix epoch
+fn to_json<S: Serialize + ?Sized>(value: &S) -> Result<serde_json::Value> {
+    serde_json::to_value(value).map_err(|err| Error::Validation(err.to_string()))
+}
+
+/// Metadata about a Sift API response
+///
+/// Useful to apply back-pressure based on Sift's rate-limit headers.
+#[derive(Clone, Debug, Default)]
+pub struct ResponseMeta {
+    /// The HTTP status code
+    ///
+    /// `0` if the http client does not report response metadata.
+    pub status: u16,
+
+    /// The response headers
+    pub headers: Vec<(String, String)>,
+}
+
+impl ResponseMeta {
+    /// Get the value of a header by case-insensitive name.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The request quota for the current rate limit window, from the `X-RateLimit-Limit` header.
+    pub fn rate_limit_limit(&self) -> Option<u64> {
+        self.header("x-ratelimit-limit")?.trim().parse().ok()
+    }
+
+    /// The remaining request quota for the current rate limit window, from the
+    /// `X-RateLimit-Remaining` header.
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self.header("x-ratelimit-remaining")?.trim().parse().ok()
+    }
+
+    /// When the current rate limit window resets, from the `X-RateLimit-Reset` header.
+    pub fn rate_limit_reset(&self) -> Option<u64> {
+        self.header("x-ratelimit-reset")?.trim().parse().ok()
+    }
+}
+
 /// Http implementation to talk to the sift API
-#[async_trait(?Send)]
+///
+/// Each method receives the extra `headers` supplied by the caller, e.g. through
+/// [EventOptions::extra_headers], which should be added to the request.
+///
+/// # Send
+///
+/// The trait does not require `Send` futures, so clients that are tied to a single thread, like
+/// `awc` under actix, can implement it. [Client] futures are `Send` whenever the futures of its
+/// HTTP client are, so a client using e.g. `reqwest` can be passed to `tokio::spawn`.
+///
+/// Generic code can't see which concrete client it has, so it should require [SendHttpClient]
+/// instead, whose futures are always `Send`. Every type implementing [SendHttpClient] implements
+/// this trait too.
+///
+/// # Trace context
+///
+/// With the `otel` feature, the built-in HTTP clients inject the current OpenTelemetry context
+/// into each request using the global text map propagator, e.g. as a `traceparent` header.
+/// Nothing is injected until a propagator is installed, and headers supplied by the caller take
+/// precedence.
 pub trait HttpClient {
     /// Create a new GET request
-    async fn get(
+    fn get(
         &self,
         url: &str,
         query_params: &QueryParams,
         timeout: Duration,
         username: Option<&str>,
-    ) -> Result<serde_json::Value>;
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<serde_json::Value>>;
 
     /// Create a new POST request
-    async fn post(
+    fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<Option<serde_json::Value>>>;
+
+    /// Create a new POST request, returning the response metadata along with the body
+    ///
+    /// The default implementation delegates to [HttpClient::post] and returns empty metadata.
+    fn post_with_meta(
         &self,
         url: &str,
         query_params: Option<&QueryParams>,
         body: Option<&serde_json::Value>,
         timeout: Duration,
         username: Option<&str>,
-    ) -> Result<Option<serde_json::Value>>;
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<(Option<serde_json::Value>, ResponseMeta)>> {
+        async move {
+            let body = self
+                .post(url, query_params, body, timeout, username, headers)
+                .await?;
+
+            Ok((body, ResponseMeta::default()))
+        }
+    }
+
+    /// Create a new POST request with an already serialized JSON body, gzipping it and sending it
+    /// with a `Content-Encoding: gzip` header, returning the response metadata along with the body
+    ///
+    /// [Client] only calls this when [HttpClient::supports_gzip] returns `true`. The default
+    /// implementation fails with an [Error::Server].
+    fn post_gzip(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        json: &[u8],
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<(Option<serde_json::Value>, ResponseMeta)>> {
+        let _ = (url, query_params, json, timeout, username, headers);
+        async { Err(Error::Server("gzipped bodies are not supported".into())) }
+    }
+
+    /// Whether [HttpClient::post_gzip] is supported
+    ///
+    /// [Client] only compresses requests when this returns `true`, which it does for the `awc`
+    /// and `reqwest` clients. Defaults to `false`.
+    fn supports_gzip(&self) -> bool {
+        false
+    }
 
     /// Create a new PUT request
-    async fn put(
+    fn put(
         &self,
         url: &str,
         body: &serde_json::Value,
         timeout: Duration,
         username: &str,
-    ) -> Result<serde_json::Value>;
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<serde_json::Value>>;
 
     /// Create a new DELETE request
-    async fn delete(&self, url: &str, timeout: Duration, username: &str) -> Result<()>;
+    ///
+    /// A `404 Not Found` response is not an error, since the resource is gone either way.
+    fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<()>>;
 }
 
-#[cfg(feature = "awc3")]
-#[async_trait(?Send)]
-impl HttpClient for awc3::Client {
-    async fn get(
+/// Http implementation to talk to the sift API, with `Send` futures
+///
+/// The same as [HttpClient], except that every future must be `Send`, so that generic code can
+/// spawn [Client] futures across threads, e.g. [EventSink::new]. Implement this trait rather than
+/// [HttpClient] for clients whose futures are `Send`; every implementation is an [HttpClient] too.
+///
+/// The `reqwest`, `reqwest-middleware`, `hyper`, `surf` and `ureq` clients implement this trait,
+/// while the `awc` clients only implement [HttpClient].
+///
+/// [EventSink::new]: crate::EventSink::new
+pub trait SendHttpClient: Send + Sync {
+    /// Create a new GET request, see [HttpClient::get]
+    fn get(
         &self,
         url: &str,
         query_params: &QueryParams,
         timeout: Duration,
         username: Option<&str>,
-    ) -> Result<serde_json::Value> {
-        let mut req = self
-            .get(url)
-            .insert_header((
-                awc3::http::header::USER_AGENT,
-                format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
-            ))
-            .timeout(timeout)
-            .query(&query_params)
-            .map_err(|err| Error::Server(err.to_string()))?;
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<serde_json::Value>> + Send;
 
-        if let Some(username) = username {
-            req = req.basic_auth(username, "");
+    /// Create a new POST request, see [HttpClient::post]
+    fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<Option<serde_json::Value>>> + Send;
+
+    /// Create a new POST request, returning the response metadata along with the body, see
+    /// [HttpClient::post_with_meta]
+    fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<(Option<serde_json::Value>, ResponseMeta)>> + Send {
+        async move {
+            let body =
+                SendHttpClient::post(self, url, query_params, body, timeout, username, headers)
+                    .await?;
+
+            Ok((body, ResponseMeta::default()))
         }
+    }
 
-        let mut res = req
-            .send()
-            .map_err(|err| {
-                tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
-            })
-            .await?;
+    /// Create a new POST request with a gzipped body, see [HttpClient::post_gzip]
+    fn post_gzip(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        json: &[u8],
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<(Option<serde_json::Value>, ResponseMeta)>> + Send {
+        let _ = (url, query_params, json, timeout, username, headers);
+        async { Err(Error::Server("gzipped bodies are not supported".into())) }
+    }
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .await
+    /// Whether [SendHttpClient::post_gzip] is supported, see [HttpClient::supports_gzip]
+    fn supports_gzip(&self) -> bool {
+        false
     }
 
-    async fn post(
+    /// Create a new PUT request, see [HttpClient::put]
+    fn put(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<serde_json::Value>> + Send;
+
+    /// Create a new DELETE request, see [HttpClient::delete]
+    fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<()>> + Send;
+}
+
+impl<T: SendHttpClient> HttpClient for T {
+    fn get(
+        &self,
+        url: &str,
+        query_params: &QueryParams,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<serde_json::Value>> {
+        SendHttpClient::get(self, url, query_params, timeout, username, headers)
+    }
+
+    fn post(
         &self,
         url: &str,
         query_params: Option<&QueryParams>,
         body: Option<&serde_json::Value>,
         timeout: Duration,
         username: Option<&str>,
-    ) -> Result<Option<serde_json::Value>> {
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<Option<serde_json::Value>>> {
+        SendHttpClient::post(self, url, query_params, body, timeout, username, headers)
+    }
+
+    fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<(Option<serde_json::Value>, ResponseMeta)>> {
+        SendHttpClient::post_with_meta(self, url, query_params, body, timeout, username, headers)
+    }
+
+    fn post_gzip(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        json: &[u8],
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<(Option<serde_json::Value>, ResponseMeta)>> {
+        SendHttpClient::post_gzip(self, url, query_params, json, timeout, username, headers)
+    }
+
+    fn supports_gzip(&self) -> bool {
+        SendHttpClient::supports_gzip(self)
+    }
+
+    fn put(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<serde_json::Value>> {
+        SendHttpClient::put(self, url, body, timeout, username, headers)
+    }
+
+    fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> impl Future<Output = Result<()>> {
+        SendHttpClient::delete(self, url, timeout, username, headers)
+    }
+}
+
+// Apply caller supplied headers to a request builder
+#[cfg(any(
+    feature = "awc",
+    feature = "awc3",
+    feature = "hyper",
+    feature = "reqwest",
+    feature = "surf",
+    feature = "ureq"
+))]
+trait WithHeaders: Sized {
+    fn with_header(self, name: &str, value: &str) -> Self;
+
+    fn with_headers(self, headers: &[(String, String)]) -> Self {
+        let req = headers
+            .iter()
+            .fold(self, |req, (name, value)| req.with_header(name, value));
+
+        #[cfg(feature = "otel")]
+        let req = trace_context_headers()
+            .into_iter()
+            .filter(|(name, _)| !headers.iter().any(|(h, _)| h.eq_ignore_ascii_case(name)))
+            .fold(req, |req, (name, value)| req.with_header(&name, &value));
+
+        req
+    }
+}
+
+// The current OpenTelemetry context as headers, e.g. `traceparent`, from the global propagator
+#[cfg(all(
+    feature = "otel",
+    any(
+        feature = "awc",
+        feature = "awc3",
+        feature = "hyper",
+        feature = "reqwest",
+        feature = "surf",
+        feature = "ureq"
+    )
+))]
+fn trace_context_headers() -> std::collections::HashMap<String, String> {
+    use opentelemetry::{global, Context};
+
+    let mut headers = std::collections::HashMap::new();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&Context::current(), &mut headers)
+    });
+    headers
+}
+
+// Body of a POST request sent by the clients that support gzip
+#[cfg(any(feature = "awc", feature = "awc3", feature = "reqwest"))]
+enum PostBody<'a> {
+    Json(&'a serde_json::Value),
+    // Serialized JSON, gzipped before sending
+    Gzip(&'a [u8]),
+}
+
+// POST request shared by `post_with_meta` and `post_gzip` of the clients that support gzip
+#[cfg(any(feature = "awc", feature = "awc3", feature = "reqwest"))]
+trait PostWithBody {
+    async fn post_with_body(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<PostBody<'_>>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)>;
+}
+
+// Gzip a serialized JSON body
+#[cfg(any(feature = "awc", feature = "awc3", feature = "reqwest"))]
+fn gzip(json: &[u8]) -> Result<Vec<u8>> {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(json)
+        .and_then(|_| encoder.finish())
+        .map_err(|err| Error::Server(err.to_string()))
+}
+
+#[cfg(feature = "awc3")]
+impl WithHeaders for awc3::ClientRequest {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.insert_header((name, value))
+    }
+}
+
+#[cfg(feature = "awc")]
+impl WithHeaders for awc::ClientRequest {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.header(name, value)
+    }
+}
+
+#[cfg(feature = "hyper")]
+impl WithHeaders for hyper::http::request::Builder {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.header(name, value)
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl WithHeaders for reqwest::RequestBuilder {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.header(name, value)
+    }
+}
+
+#[cfg(feature = "reqwest-middleware")]
+impl WithHeaders for reqwest_middleware::RequestBuilder {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.header(name, value)
+    }
+}
+
+#[cfg(feature = "surf")]
+impl WithHeaders for surf::RequestBuilder {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.header(name, value)
+    }
+}
+
+#[cfg(feature = "ureq")]
+impl WithHeaders for ureq::Request {
+    fn with_header(self, name: &str, value: &str) -> Self {
+        self.set(name, value)
+    }
+}
+
+#[cfg(feature = "awc3")]
+impl PostWithBody for awc3::Client {
+    async fn post_with_body(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<PostBody<'_>>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
         let mut req = self
             .post(url)
             .insert_header((
                 awc3::http::header::USER_AGENT,
                 format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
             ))
-            .timeout(timeout);
+            .timeout(timeout)
+            .with_headers(headers);
 
         if let Some(username) = username {
             req = req.basic_auth(username, "");
@@ -1018,44 +2519,175 @@ impl HttpClient for awc3::Client {
                 .map_err(|err| Error::Server(err.to_string()))?;
         }
 
-        let mut res = if let Some(body) = body {
-            req.send_json(&body)
-                .map_err(|err| {
-                    tracing::error!(?err, "request error");
-                    Error::Server(err.to_string())
-                })
-                .await?
-        } else {
-            req.send()
-                .map_err(|err| {
-                    tracing::error!(?err, "request error");
-                    Error::Server(err.to_string())
-                })
-                .await?
+        let mut res = match body {
+            Some(PostBody::Gzip(json)) => {
+                req.content_type("application/json")
+                    .with_header("Content-Encoding", "gzip")
+                    .send_body(gzip(json)?)
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?
+            }
+            Some(PostBody::Json(body)) => {
+                req.send_json(body)
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?
+            }
+            None => {
+                req.send()
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?
+            }
+        };
+
+        if res.status() == awc3::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc3::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
+        let meta = ResponseMeta {
+            status: res.status().as_u16(),
+            headers: res
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.into())))
+                .collect(),
         };
 
         if res.status() == awc3::http::StatusCode::NO_CONTENT {
-            return Ok(None);
+            return Ok((None, meta));
         } else if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
+            let body = res
+                .body()
+                .map_err(|err| Error::transport(err.to_string()))
                 .await?;
-            return Err(error);
+            return Err(Error::from_response(res.status().as_u16(), &body));
+        }
+
+        let body = res
+            .body()
+            .map_err(|err| Error::transport(err.to_string()))
+            .await?;
+        Ok((Some(serde_json::from_slice(&body)?), meta))
+    }
+}
+
+#[cfg(feature = "awc3")]
+impl HttpClient for awc3::Client {
+    async fn get(
+        &self,
+        url: &str,
+        query_params: &QueryParams,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        let mut req = self
+            .get(url)
+            .insert_header((
+                awc3::http::header::USER_AGENT,
+                format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+            ))
+            .timeout(timeout)
+            .with_headers(headers)
+            .query(&query_params)
+            .map_err(|err| Error::Server(err.to_string()))?;
+
+        if let Some(username) = username {
+            req = req.basic_auth(username, "");
+        }
+
+        let mut res = req
+            .send()
+            .map_err(|err| {
+                tracing::error!(?err, "request error");
+                Error::from(err)
+            })
+            .await?;
+
+        if res.status() == awc3::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc3::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
+        let body = res
+            .body()
+            .map_err(|err| Error::transport(err.to_string()))
+            .await?;
+        if !res.status().is_success() {
+            return Err(Error::from_response(res.status().as_u16(), &body));
         }
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .map_ok(Some)
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<Option<serde_json::Value>> {
+        self.post_with_meta(url, query_params, body, timeout, username, headers)
+            .await
+            .map(|(body, _)| body)
+    }
+
+    async fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let body = body.map(PostBody::Json);
+        self.post_with_body(url, query_params, body, timeout, username, headers)
+            .await
+    }
+
+    async fn post_gzip(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        json: &[u8],
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let body = Some(PostBody::Gzip(json));
+        self.post_with_body(url, query_params, body, timeout, username, headers)
             .await
     }
 
+    fn supports_gzip(&self) -> bool {
+        true
+    }
+
     async fn put(
         &self,
         url: &str,
         body: &serde_json::Value,
         timeout: Duration,
         username: &str,
+        headers: &[(String, String)],
     ) -> Result<serde_json::Value> {
         let mut res = self
             .put(url)
@@ -1065,27 +2697,44 @@ impl HttpClient for awc3::Client {
             ))
             .basic_auth(username, "")
             .timeout(timeout)
+            .with_headers(headers)
             .send_json(&body)
             .map_err(|err| {
                 tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
+                Error::from(err)
             })
             .await?;
 
+        if res.status() == awc3::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc3::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
         if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
+            let body = res
+                .body()
+                .map_err(|err| Error::transport(err.to_string()))
                 .await?;
-            return Err(error);
+            return Err(Error::from_response(res.status().as_u16(), &body));
         }
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .await
+        let body = res
+            .body()
+            .map_err(|err| Error::transport(err.to_string()))
+            .await?;
+        Ok(serde_json::from_slice(&body)?)
     }
 
-    async fn delete(&self, url: &str, timeout: Duration, username: &str) -> Result<()> {
+    async fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
         let mut res = self
             .delete(url)
             .insert_header((
@@ -1094,19 +2743,32 @@ impl HttpClient for awc3::Client {
             ))
             .basic_auth(username, "")
             .timeout(timeout)
+            .with_headers(headers)
             .send()
             .map_err(|err| {
                 tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
+                Error::from(err)
             })
             .await?;
 
+        if res.status() == awc3::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc3::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
+        if res.status() == awc3::http::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+
         if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
+            let body = res
+                .body()
+                .map_err(|err| Error::transport(err.to_string()))
                 .await?;
-            return Err(error);
+            return Err(Error::from_response(res.status().as_u16(), &body));
         }
 
         Ok(())
@@ -1118,106 +2780,204 @@ impl HttpClient for awc3::Client {
 pub type Awc3Client = Client<awc3::Client>;
 
 #[cfg(feature = "awc")]
-#[async_trait(?Send)]
-impl HttpClient for awc::Client {
-    async fn get(
+impl PostWithBody for awc::Client {
+    async fn post_with_body(
         &self,
         url: &str,
-        query_params: &QueryParams,
+        query_params: Option<&QueryParams>,
+        body: Option<PostBody<'_>>,
         timeout: Duration,
         username: Option<&str>,
-    ) -> Result<serde_json::Value> {
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
         let mut req = self
-            .get(url)
+            .post(url)
             .header(
                 awc::http::header::USER_AGENT,
                 format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
             )
             .timeout(timeout)
-            .query(&query_params)
-            .map_err(|err| Error::Server(err.to_string()))?;
+            .with_headers(headers);
 
         if let Some(username) = username {
             req = req.basic_auth(username, None);
         }
 
-        let mut res = req
-            .send()
-            .map_err(|err| {
-                tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
-            })
-            .await?;
-
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .await
-    }
+        if let Some(query_params) = query_params {
+            req = req
+                .query(&query_params)
+                .map_err(|err| Error::Server(err.to_string()))?;
+        }
 
-    async fn post(
-        &self,
-        url: &str,
-        query_params: Option<&QueryParams>,
-        body: Option<&serde_json::Value>,
+        let mut res = match body {
+            Some(PostBody::Gzip(json)) => {
+                req.content_type("application/json")
+                    .with_header("Content-Encoding", "gzip")
+                    .send_body(gzip(json)?)
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?
+            }
+            Some(PostBody::Json(body)) => {
+                req.send_json(body)
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?
+            }
+            None => {
+                req.send()
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?
+            }
+        };
+
+        if res.status() == awc::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
+        let meta = ResponseMeta {
+            status: res.status().as_u16(),
+            headers: res
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.into())))
+                .collect(),
+        };
+
+        if res.status() == awc::http::StatusCode::NO_CONTENT {
+            return Ok((None, meta));
+        } else if !res.status().is_success() {
+            let body = res
+                .body()
+                .map_err(|err| Error::transport(err.to_string()))
+                .await?;
+            return Err(Error::from_response(res.status().as_u16(), &body));
+        }
+
+        let body = res
+            .body()
+            .map_err(|err| Error::transport(err.to_string()))
+            .await?;
+        Ok((Some(serde_json::from_slice(&body)?), meta))
+    }
+}
+
+#[cfg(feature = "awc")]
+impl HttpClient for awc::Client {
+    async fn get(
+        &self,
+        url: &str,
+        query_params: &QueryParams,
         timeout: Duration,
         username: Option<&str>,
-    ) -> Result<Option<serde_json::Value>> {
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
         let mut req = self
-            .post(url)
+            .get(url)
             .header(
                 awc::http::header::USER_AGENT,
                 format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
             )
-            .timeout(timeout);
+            .timeout(timeout)
+            .with_headers(headers)
+            .query(&query_params)
+            .map_err(|err| Error::Server(err.to_string()))?;
 
         if let Some(username) = username {
             req = req.basic_auth(username, None);
         }
 
-        if let Some(query_params) = query_params {
-            req = req
-                .query(&query_params)
-                .map_err(|err| Error::Server(err.to_string()))?;
-        }
+        let mut res = req
+            .send()
+            .map_err(|err| {
+                tracing::error!(?err, "request error");
+                Error::from(err)
+            })
+            .await?;
 
-        let mut res = if let Some(body) = body {
-            req.send_json(&body)
-                .map_err(|err| {
-                    tracing::error!(?err, "request error");
-                    Error::Server(err.to_string())
-                })
-                .await?
-        } else {
-            req.send()
-                .map_err(|err| {
-                    tracing::error!(?err, "request error");
-                    Error::Server(err.to_string())
-                })
-                .await?
-        };
+        if res.status() == awc::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
 
-        if res.status() == awc::http::StatusCode::NO_CONTENT {
-            return Ok(None);
-        } else if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
-                .await?;
-            return Err(error);
+        let body = res
+            .body()
+            .map_err(|err| Error::transport(err.to_string()))
+            .await?;
+        if !res.status().is_success() {
+            return Err(Error::from_response(res.status().as_u16(), &body));
         }
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .map_ok(Some)
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<Option<serde_json::Value>> {
+        self.post_with_meta(url, query_params, body, timeout, username, headers)
+            .await
+            .map(|(body, _)| body)
+    }
+
+    async fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let body = body.map(PostBody::Json);
+        self.post_with_body(url, query_params, body, timeout, username, headers)
+            .await
+    }
+
+    async fn post_gzip(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        json: &[u8],
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let body = Some(PostBody::Gzip(json));
+        self.post_with_body(url, query_params, body, timeout, username, headers)
             .await
     }
 
+    fn supports_gzip(&self) -> bool {
+        true
+    }
+
     async fn put(
         &self,
         url: &str,
         body: &serde_json::Value,
         timeout: Duration,
         username: &str,
+        headers: &[(String, String)],
     ) -> Result<serde_json::Value> {
         let mut res = self
             .put(url)
@@ -1227,27 +2987,44 @@ impl HttpClient for awc::Client {
             )
             .basic_auth(username, None)
             .timeout(timeout)
+            .with_headers(headers)
             .send_json(&body)
             .map_err(|err| {
                 tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
+                Error::from(err)
             })
             .await?;
 
+        if res.status() == awc::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
         if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
+            let body = res
+                .body()
+                .map_err(|err| Error::transport(err.to_string()))
                 .await?;
-            return Err(error);
+            return Err(Error::from_response(res.status().as_u16(), &body));
         }
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .await
+        let body = res
+            .body()
+            .map_err(|err| Error::transport(err.to_string()))
+            .await?;
+        Ok(serde_json::from_slice(&body)?)
     }
 
-    async fn delete(&self, url: &str, timeout: Duration, username: &str) -> Result<()> {
+    async fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
         let mut res = self
             .delete(url)
             .header(
@@ -1256,19 +3033,32 @@ impl HttpClient for awc::Client {
             )
             .basic_auth(username, None)
             .timeout(timeout)
+            .with_headers(headers)
             .send()
             .map_err(|err| {
                 tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
+                Error::from(err)
             })
             .await?;
 
+        if res.status() == awc::http::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                res.headers()
+                    .get(awc::http::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
+        if res.status() == awc::http::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+
         if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
+            let body = res
+                .body()
+                .map_err(|err| Error::transport(err.to_string()))
                 .await?;
-            return Err(error);
+            return Err(Error::from_response(res.status().as_u16(), &body));
         }
 
         Ok(())
@@ -1279,41 +3069,506 @@ impl HttpClient for awc::Client {
 #[cfg(feature = "awc")]
 pub type AwcClient = Client<awc::Client>;
 
+// Implements `HttpClient` for the `reqwest` based clients, whose request builders share the same
+// API, so the requests and response handling are identical
+#[cfg(feature = "reqwest")]
+macro_rules! reqwest_http_client {
+    ($client:ty) => {
+        impl PostWithBody for $client {
+            async fn post_with_body(
+                &self,
+                url: &str,
+                query_params: Option<&QueryParams>,
+                body: Option<PostBody<'_>>,
+                timeout: Duration,
+                username: Option<&str>,
+                headers: &[(String, String)],
+            ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+                let mut req = self
+                    .post(url)
+                    .header(
+                        reqwest::header::USER_AGENT,
+                        format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+                    )
+                    .timeout(timeout)
+                    .with_headers(headers);
+
+                if let Some(username) = username {
+                    req = req.basic_auth::<_, String>(username, None);
+                }
+
+                if let Some(query_params) = query_params {
+                    req = req.query(query_params);
+                }
+
+                match body {
+                    Some(PostBody::Gzip(json)) => {
+                        req = req
+                            .header(reqwest::header::CONTENT_TYPE, "application/json")
+                            .header(reqwest::header::CONTENT_ENCODING, "gzip")
+                            .body(gzip(json)?);
+                    }
+                    Some(PostBody::Json(body)) => req = req.json(body),
+                    None => {}
+                }
+
+                let res = req
+                    .send()
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?;
+
+                if res.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    return Err(Error::rate_limited(
+                        res.headers()
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok()),
+                    ));
+                }
+
+                let meta = ResponseMeta {
+                    status: res.status().as_u16(),
+                    headers: res
+                        .headers()
+                        .iter()
+                        .filter_map(|(name, value)| {
+                            Some((name.to_string(), value.to_str().ok()?.into()))
+                        })
+                        .collect(),
+                };
+
+                if res.status() == reqwest::StatusCode::NO_CONTENT {
+                    return Ok((None, meta));
+                } else if !res.status().is_success() {
+                    let status = res.status().as_u16();
+                    let body = res.bytes().map_err(Error::from).await?;
+                    return Err(Error::from_response(status, &body));
+                }
+
+                let body = res.bytes().map_err(Error::from).await?;
+                Ok((Some(serde_json::from_slice(&body)?), meta))
+            }
+        }
+
+        impl SendHttpClient for $client {
+            async fn get(
+                &self,
+                url: &str,
+                query_params: &QueryParams,
+                timeout: Duration,
+                username: Option<&str>,
+                headers: &[(String, String)],
+            ) -> Result<serde_json::Value> {
+                let mut req = self
+                    .get(url)
+                    .header(
+                        reqwest::header::USER_AGENT,
+                        format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+                    )
+                    .query(query_params)
+                    .timeout(timeout)
+                    .with_headers(headers);
+
+                if let Some(username) = username {
+                    req = req.basic_auth::<_, String>(username, None);
+                }
+
+                let res = req
+                    .send()
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?;
+
+                if res.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    return Err(Error::rate_limited(
+                        res.headers()
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok()),
+                    ));
+                }
+
+                let status = res.status();
+                let body = res.bytes().map_err(Error::from).await?;
+                if !status.is_success() {
+                    return Err(Error::from_response(status.as_u16(), &body));
+                }
+
+                Ok(serde_json::from_slice(&body)?)
+            }
+
+            async fn post(
+                &self,
+                url: &str,
+                query_params: Option<&QueryParams>,
+                body: Option<&serde_json::Value>,
+                timeout: Duration,
+                username: Option<&str>,
+                headers: &[(String, String)],
+            ) -> Result<Option<serde_json::Value>> {
+                SendHttpClient::post_with_meta(
+                    self,
+                    url,
+                    query_params,
+                    body,
+                    timeout,
+                    username,
+                    headers,
+                )
+                .await
+                .map(|(body, _)| body)
+            }
+
+            async fn post_with_meta(
+                &self,
+                url: &str,
+                query_params: Option<&QueryParams>,
+                body: Option<&serde_json::Value>,
+                timeout: Duration,
+                username: Option<&str>,
+                headers: &[(String, String)],
+            ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+                let body = body.map(PostBody::Json);
+                self.post_with_body(url, query_params, body, timeout, username, headers)
+                    .await
+            }
+
+            async fn post_gzip(
+                &self,
+                url: &str,
+                query_params: Option<&QueryParams>,
+                json: &[u8],
+                timeout: Duration,
+                username: Option<&str>,
+                headers: &[(String, String)],
+            ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+                let body = Some(PostBody::Gzip(json));
+                self.post_with_body(url, query_params, body, timeout, username, headers)
+                    .await
+            }
+
+            fn supports_gzip(&self) -> bool {
+                true
+            }
+
+            async fn put(
+                &self,
+                url: &str,
+                body: &serde_json::Value,
+                timeout: Duration,
+                username: &str,
+                headers: &[(String, String)],
+            ) -> Result<serde_json::Value> {
+                let res = self
+                    .put(url)
+                    .header(
+                        reqwest::header::USER_AGENT,
+                        format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+                    )
+                    .basic_auth::<_, String>(username, None)
+                    .timeout(timeout)
+                    .with_headers(headers)
+                    .json(&body)
+                    .send()
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?;
+
+                if res.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    return Err(Error::rate_limited(
+                        res.headers()
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok()),
+                    ));
+                }
+
+                if !res.status().is_success() {
+                    let status = res.status().as_u16();
+                    let body = res.bytes().map_err(Error::from).await?;
+                    return Err(Error::from_response(status, &body));
+                }
+
+                let body = res.bytes().map_err(Error::from).await?;
+                Ok(serde_json::from_slice(&body)?)
+            }
+
+            async fn delete(
+                &self,
+                url: &str,
+                timeout: Duration,
+                username: &str,
+                headers: &[(String, String)],
+            ) -> Result<()> {
+                let res = self
+                    .delete(url)
+                    .header(
+                        reqwest::header::USER_AGENT,
+                        format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+                    )
+                    .basic_auth::<_, String>(username, None)
+                    .timeout(timeout)
+                    .with_headers(headers)
+                    .send()
+                    .map_err(|err| {
+                        tracing::error!(?err, "request error");
+                        Error::from(err)
+                    })
+                    .await?;
+
+                if res.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    return Err(Error::rate_limited(
+                        res.headers()
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok()),
+                    ));
+                }
+
+                if res.status() == reqwest::StatusCode::NOT_FOUND {
+                    return Ok(());
+                }
+
+                if !res.status().is_success() {
+                    let status = res.status().as_u16();
+                    let body = res.bytes().map_err(Error::from).await?;
+                    return Err(Error::from_response(status, &body));
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
 #[cfg(feature = "reqwest")]
-#[async_trait(?Send)]
-impl HttpClient for reqwest::Client {
+reqwest_http_client!(reqwest::Client);
+
+#[cfg(feature = "reqwest-middleware")]
+reqwest_http_client!(reqwest_middleware::ClientWithMiddleware);
+
+/// Sift client using `reqwest` as http client
+#[cfg(feature = "reqwest")]
+pub type ReqwestClient = Client<reqwest::Client>;
+
+#[cfg(feature = "reqwest")]
+impl Client<reqwest::Client> {
+    /// construct a new client with a given api key, using a `reqwest` HTTP client with separate
+    /// connect and total timeouts.
+    ///
+    /// The total timeout is also the client's [default timeout](Client::default_timeout), which
+    /// calls can override with their options.
+    ///
+    /// ```
+    /// use sift_science::{ReqwestClient, Timeouts};
+    /// use std::time::Duration;
+    ///
+    /// let sift = ReqwestClient::with_timeouts(
+    ///     "api_key",
+    ///     Timeouts::new(Duration::from_secs(10)).with_connect(Duration::from_millis(250)),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(sift.default_timeout, Duration::from_secs(10));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This errors if the underlying `reqwest` client cannot be built, e.g. if the TLS backend
+    /// fails to initialize.
+    pub fn with_timeouts(api_key: impl Into<String>, timeouts: Timeouts) -> Result<Self> {
+        let http_client = timeouts
+            .configure(reqwest::Client::builder())
+            .build()
+            .map_err(|err| Error::Server(err.to_string()))?;
+
+        Ok(Client::builder(api_key, http_client)
+            .default_timeout(timeouts.total)
+            .build())
+    }
+
+    /// construct a new client with a given api key and a `reqwest` HTTP client tuned for
+    /// high-throughput event ingestion.
+    ///
+    /// The recommended configuration for talking to Sift is:
+    ///
+    /// * Keep up to 32 idle connections per host, and hold on to them for 90 seconds, so bursts of
+    ///   events reuse warm connections instead of renegotiating TLS.
+    /// * Enable TCP keep-alive every 60 seconds so intermediaries don't silently drop idle pooled
+    ///   connections.
+    /// * Send HTTP/2 keep-alive pings every 30 seconds, even while idle, and give up on the
+    ///   connection after 10 seconds without a reply.
+    ///
+    /// HTTP/2 is negotiated with the Sift API over TLS (ALPN), so `http2_prior_knowledge` is not
+    /// required and should not be set.
+    ///
+    /// # Errors
+    ///
+    /// This errors if the underlying `reqwest` client cannot be built, e.g. if the TLS backend
+    /// fails to initialize.
+    pub fn tuned(api_key: impl Into<String>) -> Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .pool_max_idle_per_host(32)
+            .pool_idle_timeout(Duration::from_secs(90))
+            .tcp_keepalive(Duration::from_secs(60))
+            .http2_keep_alive_interval(Duration::from_secs(30))
+            .http2_keep_alive_timeout(Duration::from_secs(10))
+            .http2_keep_alive_while_idle(true)
+            .build()
+            .map_err(|err| Error::Server(err.to_string()))?;
+
+        Ok(Client::new(api_key, http_client))
+    }
+}
+
+/// Sift client using `reqwest-middleware` as http client, so Sift calls run through the same
+/// middleware, e.g. tracing or retries, as other `reqwest` requests
+///
+/// Requests are built and responses handled exactly as with [ReqwestClient].
+#[cfg(feature = "reqwest-middleware")]
+pub type ReqwestMiddlewareClient = Client<reqwest_middleware::ClientWithMiddleware>;
+
+// A response read in full by the `hyper` client
+#[cfg(feature = "hyper")]
+struct HyperResponse {
+    status: hyper::StatusCode,
+    headers: hyper::HeaderMap,
+    body: hyper::body::Bytes,
+}
+
+#[cfg(feature = "hyper")]
+impl HyperResponse {
+    // Map non-2xx responses into a rate limit error, or the sift error in the body
+    fn error_for_status(self) -> Result<Self> {
+        if self.status == hyper::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::rate_limited(
+                self.headers
+                    .get(hyper::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok()),
+            ));
+        }
+
+        if !self.status.is_success() {
+            return Err(Error::from_response(self.status.as_u16(), &self.body));
+        }
+
+        Ok(self)
+    }
+
+    fn json(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+#[cfg(feature = "hyper")]
+#[allow(clippy::too_many_arguments)]
+async fn hyper_send<C>(
+    client: &hyper::Client<C>,
+    method: hyper::Method,
+    url: &str,
+    query_params: Option<&QueryParams>,
+    body: Option<&serde_json::Value>,
+    timeout: Duration,
+    username: Option<&str>,
+    headers: &[(String, String)],
+) -> Result<HyperResponse>
+where
+    C: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+{
+    use base64::Engine;
+
+    let url = match query_params {
+        Some(query_params) => {
+            let query = serde_urlencoded::to_string(query_params)
+                .map_err(|err| Error::Server(err.to_string()))?;
+            if query.is_empty() {
+                url.to_string()
+            } else {
+                format!("{}?{}", url, query)
+            }
+        }
+        None => url.to_string(),
+    };
+
+    let mut req = hyper::Request::builder()
+        .method(method)
+        .uri(url)
+        .header(
+            hyper::header::USER_AGENT,
+            format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+        )
+        .with_headers(headers);
+
+    if let Some(username) = username {
+        let credentials =
+            base64::engine::general_purpose::STANDARD.encode(format!("{}:", username));
+        req = req.header(
+            hyper::header::AUTHORIZATION,
+            format!("Basic {}", credentials),
+        );
+    }
+
+    let req = match body {
+        Some(body) => req
+            .header(hyper::header::CONTENT_TYPE, "application/json")
+            .body(hyper::Body::from(serde_json::to_vec(body)?)),
+        None => req.body(hyper::Body::empty()),
+    }
+    .map_err(|err| Error::Server(err.to_string()))?;
+
+    let send = async {
+        let res = client.request(req).await?;
+        let (parts, body) = res.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+
+        Ok::<_, hyper::Error>(HyperResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        })
+    };
+
+    tokio::time::timeout(timeout, send)
+        .await
+        .map_err(|err| {
+            tracing::error!(?err, "request timeout");
+            Error::timeout(err)
+        })?
+        .map_err(|err| {
+            tracing::error!(?err, "request error");
+            Error::transport(err)
+        })
+}
+
+#[cfg(feature = "hyper")]
+impl<C> SendHttpClient for hyper::Client<C>
+where
+    C: hyper::client::connect::Connect + Clone + Send + Sync + 'static,
+{
     async fn get(
         &self,
         url: &str,
         query_params: &QueryParams,
         timeout: Duration,
         username: Option<&str>,
+        headers: &[(String, String)],
     ) -> Result<serde_json::Value> {
-        let mut req = self
-            .get(url)
-            .header(
-                reqwest::header::USER_AGENT,
-                format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
-            )
-            .query(query_params)
-            .timeout(timeout);
-
-        if let Some(username) = username {
-            req = req.basic_auth::<_, String>(username, None);
-        }
-
-        let res = req
-            .query(&query_params)
-            .send()
-            .map_err(|err| {
-                tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
-            })
-            .await?;
-
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .await
+        hyper_send(
+            self,
+            hyper::Method::GET,
+            url,
+            Some(query_params),
+            None,
+            timeout,
+            username,
+            headers,
+        )
+        .await?
+        .error_for_status()?
+        .json()
     }
 
     async fn post(
@@ -1323,49 +3578,285 @@ impl HttpClient for reqwest::Client {
         body: Option<&serde_json::Value>,
         timeout: Duration,
         username: Option<&str>,
+        headers: &[(String, String)],
     ) -> Result<Option<serde_json::Value>> {
-        let mut req = self
-            .post(url)
-            .header(
-                reqwest::header::USER_AGENT,
-                format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
-            )
-            .timeout(timeout);
+        SendHttpClient::post_with_meta(self, url, query_params, body, timeout, username, headers)
+            .await
+            .map(|(body, _)| body)
+    }
 
-        if let Some(username) = username {
-            req = req.basic_auth::<_, String>(username, None);
+    async fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let res = hyper_send(
+            self,
+            hyper::Method::POST,
+            url,
+            query_params,
+            body,
+            timeout,
+            username,
+            headers,
+        )
+        .await?;
+
+        let meta = ResponseMeta {
+            status: res.status.as_u16(),
+            headers: res
+                .headers
+                .iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.into())))
+                .collect(),
+        };
+
+        if res.status == hyper::StatusCode::NO_CONTENT {
+            return Ok((None, meta));
         }
 
-        if let Some(query_params) = query_params {
-            req = req.query(query_params);
+        Ok((Some(res.error_for_status()?.json()?), meta))
+    }
+
+    async fn put(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        hyper_send(
+            self,
+            hyper::Method::PUT,
+            url,
+            None,
+            Some(body),
+            timeout,
+            Some(username),
+            headers,
+        )
+        .await?
+        .error_for_status()?
+        .json()
+    }
+
+    async fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
+        let res = hyper_send(
+            self,
+            hyper::Method::DELETE,
+            url,
+            None,
+            None,
+            timeout,
+            Some(username),
+            headers,
+        )
+        .await?;
+
+        if res.status == hyper::StatusCode::NOT_FOUND {
+            return Ok(());
         }
 
-        if let Some(body) = body {
-            req = req.json(&body);
+        res.error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Sift client using `hyper` with a `rustls` HTTPS connector as http client
+///
+/// [HttpClient] is implemented for `hyper::Client<C>` with any connector, so a client built on a
+/// custom connector can be passed to [Client::new] directly.
+#[cfg(feature = "hyper")]
+pub type HyperClient =
+    Client<hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>, hyper::Body>>;
+
+#[cfg(feature = "hyper")]
+impl HyperClient {
+    /// construct a new client with a given api key and a `hyper` client using `rustls` with the
+    /// webpki root certificates.
+    pub fn with_https(api_key: impl Into<String>) -> Self {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .enable_http2()
+            .build();
+
+        Client::new(api_key, hyper::Client::builder().build(connector))
+    }
+}
+
+#[cfg(feature = "ureq")]
+fn ureq_request(
+    agent: &ureq::Agent,
+    method: &str,
+    url: &str,
+    query_params: Option<&QueryParams>,
+    timeout: Duration,
+    username: Option<&str>,
+    headers: &[(String, String)],
+) -> Result<ureq::Request> {
+    use base64::Engine;
+
+    let url = match query_params {
+        Some(query_params) => {
+            let query = serde_urlencoded::to_string(query_params)
+                .map_err(|err| Error::Server(err.to_string()))?;
+            if query.is_empty() {
+                url.to_string()
+            } else {
+                format!("{}?{}", url, query)
+            }
         }
+        None => url.to_string(),
+    };
+
+    let mut req = agent
+        .request(method, &url)
+        .set(
+            "User-Agent",
+            &format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+        )
+        .timeout(timeout)
+        .with_headers(headers);
+
+    if let Some(username) = username {
+        let credentials =
+            base64::engine::general_purpose::STANDARD.encode(format!("{}:", username));
+        req = req.set("Authorization", &format!("Basic {}", credentials));
+    }
 
-        let res = req
-            .send()
-            .map_err(|err| {
-                tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
-            })
-            .await?;
+    Ok(req)
+}
 
-        if res.status() == reqwest::StatusCode::NO_CONTENT {
-            return Ok(None);
-        } else if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
-                .await?;
-            return Err(error);
+#[cfg(feature = "ureq")]
+fn ureq_response(res: std::result::Result<ureq::Response, ureq::Error>) -> Result<ureq::Response> {
+    match res {
+        Ok(res) => Ok(res),
+        Err(ureq::Error::Status(429, res)) => Err(Error::rate_limited(res.header("Retry-After"))),
+        Err(ureq::Error::Status(status, res)) => {
+            let body = res.into_string().map_err(Error::transport)?;
+            Err(Error::from_response(status, body.as_bytes()))
         }
+        Err(err) => {
+            tracing::error!(?err, "request error");
+            Err(Error::transport(err))
+        }
+    }
+}
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
-            .map_ok(Some)
+/// Blocking http client using `ureq`.
+///
+/// `ureq` performs blocking I/O, so the futures returned by [Client] methods complete
+/// synchronously the first time they are polled. Drive them with a lightweight executor such as
+/// `futures::executor::block_on` rather than an async runtime, which would be blocked for the
+/// duration of each request:
+///
+/// ```no_run
+/// use futures::executor::block_on;
+/// use sift_science::{
+///     events::{Event, EventOptions, LoginProperties},
+///     UreqClient,
+/// };
+///
+/// let sift = UreqClient::new("api_key", ureq::Agent::new());
+///
+/// let scores = block_on(sift.track(
+///     Event::Login {
+///         user_id: "billy_jones_301".into(),
+///         session_id: None,
+///         properties: LoginProperties::default(),
+///     },
+///     EventOptions::default(),
+/// ));
+/// ```
+#[cfg(feature = "ureq")]
+impl SendHttpClient for ureq::Agent {
+    async fn get(
+        &self,
+        url: &str,
+        query_params: &QueryParams,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        let req = ureq_request(
+            self,
+            "GET",
+            url,
+            Some(query_params),
+            timeout,
+            username,
+            headers,
+        )?;
+
+        let body = ureq_response(req.call())?
+            .into_string()
+            .map_err(Error::transport)?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<Option<serde_json::Value>> {
+        SendHttpClient::post_with_meta(self, url, query_params, body, timeout, username, headers)
             .await
+            .map(|(body, _)| body)
+    }
+
+    async fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let req = ureq_request(self, "POST", url, query_params, timeout, username, headers)?;
+
+        let res = if let Some(body) = body {
+            ureq_response(req.send_json(body))?
+        } else {
+            ureq_response(req.call())?
+        };
+
+        let meta = ResponseMeta {
+            status: res.status(),
+            headers: res
+                .headers_names()
+                .into_iter()
+                .filter_map(|name| {
+                    let value = res.header(&name)?.to_string();
+                    Some((name, value))
+                })
+                .collect(),
+        };
+
+        if res.status() == 204 {
+            return Ok((None, meta));
+        }
+
+        let body = res.into_string().map_err(Error::transport)?;
+        Ok((Some(serde_json::from_str(&body)?), meta))
     }
 
     async fn put(
@@ -1374,64 +3865,245 @@ impl HttpClient for reqwest::Client {
         body: &serde_json::Value,
         timeout: Duration,
         username: &str,
+        headers: &[(String, String)],
     ) -> Result<serde_json::Value> {
-        let res = self
-            .put(url)
-            .header(
-                reqwest::header::USER_AGENT,
-                format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
-            )
-            .basic_auth::<_, String>(username, None)
-            .timeout(timeout)
-            .json(&body)
-            .send()
-            .map_err(|err| {
-                tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
-            })
-            .await?;
+        let req = ureq_request(self, "PUT", url, None, timeout, Some(username), headers)?;
 
-        if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
-                .await?;
-            return Err(error);
+        let body = ureq_response(req.send_json(body))?
+            .into_string()
+            .map_err(Error::transport)?;
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    async fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
+        let req = ureq_request(self, "DELETE", url, None, timeout, Some(username), headers)?;
+
+        match req.call() {
+            Err(ureq::Error::Status(404, _)) => Ok(()),
+            res => ureq_response(res).map(|_| ()),
         }
+    }
+}
+
+/// Sift client using `ureq` as a blocking http client
+///
+/// See the [SendHttpClient] implementation for `ureq::Agent` for how to call it synchronously.
+#[cfg(feature = "ureq")]
+pub type UreqClient = Client<ureq::Agent>;
+
+#[cfg(feature = "surf")]
+fn surf_request(
+    method: surf::http::Method,
+    url: &str,
+    query_params: Option<&QueryParams>,
+    username: Option<&str>,
+    headers: &[(String, String)],
+) -> Result<surf::RequestBuilder> {
+    use base64::Engine;
+
+    let url = surf::Url::parse(url).map_err(|err| Error::Server(err.to_string()))?;
+    let mut req = surf::RequestBuilder::new(method, url)
+        .header(
+            "User-Agent",
+            format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
+        )
+        .with_headers(headers);
+
+    if let Some(query_params) = query_params {
+        req = req
+            .query(query_params)
+            .map_err(|err| Error::Server(err.to_string()))?;
+    }
+
+    if let Some(username) = username {
+        let credentials =
+            base64::engine::general_purpose::STANDARD.encode(format!("{}:", username));
+        req = req.header("Authorization", format!("Basic {}", credentials));
+    }
+
+    Ok(req)
+}
+
+#[cfg(feature = "surf")]
+async fn surf_response(
+    client: &surf::Client,
+    req: surf::RequestBuilder,
+    timeout: Duration,
+) -> Result<surf::Response> {
+    surf_error_for_status(surf_send(client, req, timeout).await?).await
+}
+
+#[cfg(feature = "surf")]
+async fn surf_send(
+    client: &surf::Client,
+    req: surf::RequestBuilder,
+    timeout: Duration,
+) -> Result<surf::Response> {
+    async_std::future::timeout(timeout, client.send(req))
+        .await
+        .map_err(|err| {
+            tracing::error!(?err, "request timeout");
+            Error::timeout(err)
+        })?
+        .map_err(|err| {
+            tracing::error!(?err, "request error");
+            Error::transport(err.to_string())
+        })
+}
 
-        res.json()
-            .map_err(|err| Error::Server(err.to_string()))
+// Map non-2xx responses into a rate limit error, or the sift error in the body
+#[cfg(feature = "surf")]
+async fn surf_error_for_status(mut res: surf::Response) -> Result<surf::Response> {
+    if res.status() == surf::StatusCode::TooManyRequests {
+        return Err(Error::rate_limited(
+            res.header("Retry-After").map(|value| value.as_str()),
+        ));
+    }
+
+    if !res.status().is_success() {
+        let body = res
+            .body_bytes()
             .await
+            .map_err(|err| Error::transport(err.to_string()))?;
+        return Err(Error::from_response(res.status().into(), &body));
     }
 
-    async fn delete(&self, url: &str, timeout: Duration, username: &str) -> Result<()> {
-        let res = self
-            .delete(url)
-            .header(
-                reqwest::header::USER_AGENT,
-                format!("sift-rust/{}", env!("CARGO_PKG_VERSION")),
-            )
-            .basic_auth::<_, String>(username, None)
-            .timeout(timeout)
-            .send()
-            .map_err(|err| {
-                tracing::error!(?err, "request error");
-                Error::Server(err.to_string())
-            })
-            .await?;
+    Ok(res)
+}
 
-        if !res.status().is_success() {
-            let error: Error = res
-                .json()
-                .map_err(|err| Error::Server(err.to_string()))
-                .await?;
-            return Err(error);
+#[cfg(feature = "surf")]
+async fn surf_body(mut res: surf::Response) -> Result<serde_json::Value> {
+    let body = res
+        .body_bytes()
+        .await
+        .map_err(|err| Error::transport(err.to_string()))?;
+    Ok(serde_json::from_slice(&body)?)
+}
+
+#[cfg(feature = "surf")]
+impl SendHttpClient for surf::Client {
+    async fn get(
+        &self,
+        url: &str,
+        query_params: &QueryParams,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        let req = surf_request(
+            surf::http::Method::Get,
+            url,
+            Some(query_params),
+            username,
+            headers,
+        )?;
+
+        surf_body(surf_response(self, req, timeout).await?).await
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<Option<serde_json::Value>> {
+        SendHttpClient::post_with_meta(self, url, query_params, body, timeout, username, headers)
+            .await
+            .map(|(body, _)| body)
+    }
+
+    async fn post_with_meta(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let mut req = surf_request(
+            surf::http::Method::Post,
+            url,
+            query_params,
+            username,
+            headers,
+        )?;
+
+        if let Some(body) = body {
+            req = req
+                .body_json(body)
+                .map_err(|err| Error::Server(err.to_string()))?;
         }
 
+        let res = surf_response(self, req, timeout).await?;
+
+        let meta = ResponseMeta {
+            status: res.status().into(),
+            headers: res
+                .iter()
+                .map(|(name, values)| (name.to_string(), values.last().to_string()))
+                .collect(),
+        };
+
+        if res.status() == surf::StatusCode::NoContent {
+            return Ok((None, meta));
+        }
+
+        Ok((Some(surf_body(res).await?), meta))
+    }
+
+    async fn put(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        let req = surf_request(surf::http::Method::Put, url, None, Some(username), headers)?
+            .body_json(body)
+            .map_err(|err| Error::Server(err.to_string()))?;
+
+        surf_body(surf_response(self, req, timeout).await?).await
+    }
+
+    async fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
+        let req = surf_request(
+            surf::http::Method::Delete,
+            url,
+            None,
+            Some(username),
+            headers,
+        )?;
+
+        let res = surf_send(self, req, timeout).await?;
+        if res.status() == surf::StatusCode::NotFound {
+            return Ok(());
+        }
+
+        surf_error_for_status(res).await?;
+
         Ok(())
     }
 }
 
-/// Sift client using `reqwest` as http client
-#[cfg(feature = "reqwest")]
-pub type ReqwestClient = Client<reqwest::Client>;
+/// Sift client using `surf` as http client, for `async-std` based applications
+///
+/// Non-2xx responses are mapped into an [Error] from the Sift error body.
+#[cfg(feature = "surf")]
+pub type SurfClient = Client<surf::Client>;
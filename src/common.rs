@@ -3,34 +3,157 @@ use std::fmt;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Type of abuse tracked by a sift science.
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// Each variant serializes, and displays, as the wire value Sift uses in query params and as the
+/// key in score and decision responses.
+///
+/// ```
+/// use sift_science::AbuseType;
+///
+/// for (abuse_type, wire) in [
+///     (AbuseType::AccountTakeover, "account_takeover"),
+///     (AbuseType::AccountAbuse, "account_abuse"),
+///     (AbuseType::ContentAbuse, "content_abuse"),
+///     (AbuseType::PaymentAbuse, "payment_abuse"),
+///     (AbuseType::PromoAbuse, "promotion_abuse"),
+///     (AbuseType::Legacy, "legacy"),
+/// ] {
+///     assert_eq!(abuse_type.to_string(), wire);
+///     assert_eq!(abuse_type.as_str(), wire);
+///     assert_eq!(AbuseType::from_wire(wire), Some(abuse_type));
+///     assert_eq!(serde_json::to_value(abuse_type).unwrap(), wire);
+///     assert_eq!(
+///         serde_json::from_value::<AbuseType>(wire.into()).unwrap(),
+///         abuse_type
+///     );
+///
+///     // Response keys, e.g. in `Scores`, use the same wire value
+///     let scores: sift_science::events::Scores =
+///         serde_json::from_value(serde_json::json!({ wire: { "score": 0.5 } })).unwrap();
+///     assert!(serde_json::to_value(&scores).unwrap().get(wire).is_some());
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum AbuseType {
-    /// Account takeover
+    /// Account takeover, `account_takeover` on the wire
+    #[serde(rename = "account_takeover")]
     AccountTakeover,
 
-    /// Account abuse
+    /// Account abuse, `account_abuse` on the wire
+    #[serde(rename = "account_abuse")]
     AccountAbuse,
 
-    /// Content abuse
+    /// Content abuse, `content_abuse` on the wire
+    #[serde(rename = "content_abuse")]
     ContentAbuse,
 
-    /// Payment abuse
+    /// Payment abuse, `payment_abuse` on the wire
+    #[serde(rename = "payment_abuse")]
     PaymentAbuse,
 
-    /// Promo abuse
+    /// Promo abuse, `promotion_abuse` on the wire
+    #[serde(rename = "promotion_abuse", alias = "promo_abuse")]
     PromoAbuse,
+
+    /// The legacy Sift Score, for custom fraud types, `legacy` on the wire
+    #[serde(rename = "legacy")]
+    Legacy,
+}
+
+impl AbuseType {
+    /// Every abuse type, in the order Sift lists them in responses.
+    ///
+    /// ```
+    /// use sift_science::AbuseType;
+    ///
+    /// for abuse_type in AbuseType::ALL {
+    ///     assert_eq!(AbuseType::from_wire(abuse_type.as_str()), Some(abuse_type));
+    /// }
+    /// assert_eq!(AbuseType::from_wire("promo_abuse"), Some(AbuseType::PromoAbuse));
+    /// assert_eq!(AbuseType::from_wire("PAYMENT_ABUSE"), None);
+    /// assert_eq!(AbuseType::from_wire("unknown_abuse"), None);
+    /// ```
+    pub const ALL: [AbuseType; 6] = [
+        AbuseType::PaymentAbuse,
+        AbuseType::PromoAbuse,
+        AbuseType::AccountAbuse,
+        AbuseType::AccountTakeover,
+        AbuseType::ContentAbuse,
+        AbuseType::Legacy,
+    ];
+
+    /// The value Sift uses for this abuse type in query params and response keys.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbuseType::AccountAbuse => "account_abuse",
+            AbuseType::AccountTakeover => "account_takeover",
+            AbuseType::ContentAbuse => "content_abuse",
+            AbuseType::PaymentAbuse => "payment_abuse",
+            AbuseType::PromoAbuse => "promotion_abuse",
+            AbuseType::Legacy => "legacy",
+        }
+    }
+
+    /// The abuse type for a value Sift uses in query params and response keys, including the
+    /// older `promo_abuse`.
+    pub fn from_wire(wire: &str) -> Option<Self> {
+        match wire {
+            "promo_abuse" => Some(AbuseType::PromoAbuse),
+            _ => AbuseType::ALL
+                .into_iter()
+                .find(|abuse_type| abuse_type.as_str() == wire),
+        }
+    }
 }
 
 impl fmt::Display for AbuseType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            AbuseType::AccountAbuse => write!(f, "account_abuse"),
-            AbuseType::AccountTakeover => write!(f, "account_takeover"),
-            AbuseType::ContentAbuse => write!(f, "content_abuse"),
-            AbuseType::PaymentAbuse => write!(f, "payment_abuse"),
-            AbuseType::PromoAbuse => write!(f, "promo_abuse"),
+        f.write_str(self.as_str())
+    }
+}
+
+// Reserved fields masked in logged request bodies
+const PII_FIELDS: &[&str] = &[
+    "$user_email",
+    "$phone",
+    "$card_bin",
+    "$card_last4",
+    "$avs_result_code",
+];
+
+// Credentials masked in logged request bodies, whether or not PII is redacted
+const SECRET_FIELDS: &[&str] = &["$api_key"];
+
+// A request body for logging, with secrets and optionally PII fields masked when formatted
+pub(crate) struct Redacted<'a> {
+    pub(crate) body: &'a serde_json::Value,
+    pub(crate) redact: bool,
+}
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut body = self.body.clone();
+        redact(&mut body, self.redact);
+        write!(f, "{}", body)
+    }
+}
+
+// Mask secret and PII fields at any depth, e.g. in nested addresses and payment methods
+fn redact(value: &mut serde_json::Value, pii: bool) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                let masked = SECRET_FIELDS.contains(&key.as_str())
+                    || (pii && PII_FIELDS.contains(&key.as_str()));
+                if masked && !value.is_null() {
+                    *value = serde_json::Value::String("[REDACTED]".into());
+                } else {
+                    redact(value, pii);
+                }
+            }
         }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(|value| redact(value, pii)),
+        _ => {}
     }
 }
 
@@ -58,6 +181,37 @@ where
     }
 }
 
+// Deserialize flattened extra fields, treating an empty set of fields as `None` so that events
+// round-trip
+pub(crate) fn deserialize_extra<'de, D>(d: D) -> Result<Option<serde_json::Value>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let extra = serde_json::Map::deserialize(d)?;
+
+    Ok(if extra.is_empty() {
+        None
+    } else {
+        Some(serde_json::Value::Object(extra))
+    })
+}
+
+// Skip serializing lists that are unset or empty, which Sift flags as invalid
+pub(crate) fn is_none_or_empty<T>(list: &Option<Vec<T>>) -> bool {
+    list.as_ref().is_none_or(Vec::is_empty)
+}
+
+// Tag a user id for tracing spans, so traces can be correlated by user without recording the id.
+// FNV-1a, so the tag is the same across processes and releases. It is not a cryptographic hash.
+pub(crate) fn hash_user_id(user_id: &str) -> String {
+    let hash = user_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
 // Deserialize optional system time as timestamp in ms
 pub(crate) fn deserialize_opt_ms<'de, D>(d: D) -> Result<Option<SystemTime>, D::Error>
 where
@@ -89,14 +243,70 @@ where
     }
 }
 
-// Serialize system time as timestamp in ms
+// Serialize system time as timestamp in ms, failing for times that can't be represented rather
+// than sending a wrong timestamp
 pub(crate) fn serialize_ms<S>(time: &SystemTime, s: S) -> Result<S::Ok, S::Error>
 where
     S: ser::Serializer,
 {
-    s.serialize_u64(
-        time.duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64,
-    )
+    let ms = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|err| {
+            ser::Error::custom(format!(
+                "time is {:?} before the unix epoch",
+                err.duration()
+            ))
+        })?
+        .as_millis();
+    let ms = u64::try_from(ms)
+        .map_err(|_| ser::Error::custom(format!("time is {} ms after the unix epoch", ms)))?;
+
+    s.serialize_u64(ms)
+}
+
+/// Parse an [RFC 3339] timestamp, e.g. `"2016-04-29T20:57:19.151Z"`, for the `SystemTime` fields
+/// of events and decisions.
+///
+/// These fields are still sent as milliseconds since the unix epoch. A `chrono::DateTime` can also
+/// be converted into a `SystemTime` directly with `into()`.
+///
+/// Only available with the `chrono` feature.
+///
+/// ```
+/// use sift_science::{events::ListingProperties, parse_rfc3339, Error};
+///
+/// let listing = ListingProperties {
+///     expiration_time: Some(parse_rfc3339("2016-04-29T20:57:19.151Z").unwrap()),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     serde_json::to_value(&listing).unwrap()["$expiration_time"],
+///     1461963439151u64
+/// );
+///
+/// // Offsets are applied
+/// assert_eq!(
+///     parse_rfc3339("2016-04-29T13:57:19.151-07:00").unwrap(),
+///     listing.expiration_time.unwrap()
+/// );
+///
+/// let expiration_time = chrono::DateTime::parse_from_rfc3339("2016-04-29T20:57:19.151Z")
+///     .unwrap()
+///     .with_timezone(&chrono::Utc);
+/// assert_eq!(Some(expiration_time.into()), listing.expiration_time);
+///
+/// assert!(matches!(
+///     parse_rfc3339("April 29th, 2016"),
+///     Err(Error::Validation(_))
+/// ));
+/// ```
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+#[cfg(feature = "chrono")]
+pub fn parse_rfc3339(timestamp: &str) -> crate::Result<SystemTime> {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(SystemTime::from)
+        .map_err(|err| {
+            crate::Error::Validation(format!("invalid timestamp {:?}: {}", timestamp, err))
+        })
 }
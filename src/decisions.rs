@@ -33,13 +33,44 @@
 
 use crate::{
     common::{deserialize_ms, serialize_opt_ms},
-    AbuseType, Error,
+    AbuseType, Error, Page, Result,
 };
 use serde::{Deserialize, Serialize};
-use std::{fmt, time::SystemTime};
+use std::{
+    fmt,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
 
 /// A sift entity about which decisions can be made
-#[derive(Debug)]
+///
+/// Entities display as their path in the Decisions API, and parse back from it.
+///
+/// ```
+/// use sift_science::{decisions::Entity, Error};
+///
+/// for entity in [
+///     Entity::user("billy_jones_301"),
+///     Entity::order("billy_jones_301", "ORDER-28168441"),
+///     Entity::session("billy_jones_301", "gigtleqddo84l8cm15qe4il"),
+///     Entity::content("billy_jones_301", "9671500641"),
+/// ] {
+///     assert_eq!(entity.to_string().parse::<Entity>().unwrap(), entity);
+/// }
+///
+/// assert_eq!(
+///     "users/u1/orders/o1".parse::<Entity>().unwrap(),
+///     Entity::Order {
+///         user_id: "u1".into(),
+///         order_id: "o1".into(),
+///     }
+/// );
+///
+/// for path in ["", "users", "users/u1/carts/c1", "orders/o1", "users/u1/orders"] {
+///     assert!(matches!(path.parse::<Entity>(), Err(Error::Validation(_))));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Entity {
     /// Decisions about a user.
     User {
@@ -73,7 +104,7 @@ pub enum Entity {
 }
 
 /// The types of entities about which decisions can be made.
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum EntityType {
     /// Decisions applied to users.
@@ -89,6 +120,58 @@ pub enum EntityType {
     Content,
 }
 
+impl Entity {
+    /// A user entity.
+    pub fn user(user_id: impl Into<String>) -> Self {
+        Entity::User {
+            user_id: user_id.into(),
+        }
+    }
+
+    /// An order entity.
+    pub fn order(user_id: impl Into<String>, order_id: impl Into<String>) -> Self {
+        Entity::Order {
+            user_id: user_id.into(),
+            order_id: order_id.into(),
+        }
+    }
+
+    /// A session entity.
+    pub fn session(user_id: impl Into<String>, session_id: impl Into<String>) -> Self {
+        Entity::Session {
+            user_id: user_id.into(),
+            session_id: session_id.into(),
+        }
+    }
+
+    /// A content entity.
+    pub fn content(user_id: impl Into<String>, content_id: impl Into<String>) -> Self {
+        Entity::Content {
+            user_id: user_id.into(),
+            content_id: content_id.into(),
+        }
+    }
+}
+
+impl FromStr for Entity {
+    type Err = Error;
+
+    fn from_str(path: &str) -> Result<Self> {
+        let segments = path.split('/').collect::<Vec<_>>();
+
+        match segments.as_slice() {
+            ["users", user_id] if !user_id.is_empty() => Ok(Entity::user(*user_id)),
+            ["users", user_id, kind, id] if !user_id.is_empty() && !id.is_empty() => match *kind {
+                "orders" => Ok(Entity::order(*user_id, *id)),
+                "sessions" => Ok(Entity::session(*user_id, *id)),
+                "content" => Ok(Entity::content(*user_id, *id)),
+                _ => Err(Error::Validation(format!("unknown entity path: {}", path))),
+            },
+            _ => Err(Error::Validation(format!("unknown entity path: {}", path))),
+        }
+    }
+}
+
 impl fmt::Display for Entity {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -109,6 +192,37 @@ impl fmt::Display for Entity {
 }
 
 /// Used to apply new decisions
+///
+/// Times are sent as milliseconds since the unix epoch. Times before the epoch, or too far in the
+/// future to fit in a `u64`, fail to serialize rather than being sent as the wrong time.
+///
+/// ```
+/// use sift_science::decisions::{DecisionRequest, Source};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let decision = |time| DecisionRequest {
+///     decision_id: "block_user_payment_abuse".into(),
+///     source: Source::AutomatedRule,
+///     analyst: None,
+///     time: Some(time),
+///     description: None,
+/// };
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(1461963439151);
+/// assert_eq!(
+///     serde_json::to_value(decision(time)).unwrap()["time"],
+///     1461963439151u64
+/// );
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(u64::MAX);
+/// assert_eq!(serde_json::to_value(decision(time)).unwrap()["time"], u64::MAX);
+///
+/// let time = UNIX_EPOCH + Duration::from_millis(u64::MAX) + Duration::from_millis(1);
+/// assert!(serde_json::to_value(decision(time)).is_err());
+///
+/// let time = UNIX_EPOCH - Duration::from_secs(1);
+/// assert!(serde_json::to_value(decision(time)).is_err());
+/// ```
 #[derive(Debug, Serialize)]
 pub struct DecisionRequest {
     /// The unique identifier of the decision to be applied to an entity.
@@ -137,6 +251,111 @@ pub struct DecisionRequest {
     pub description: Option<String>,
 }
 
+impl DecisionRequest {
+    /// A decision applied by an analyst during review, which Sift requires to name the analyst.
+    ///
+    /// ```
+    /// use sift_science::decisions::{DecisionRequest, Source};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let decision = DecisionRequest::manual_review("block_user_payment_abuse", "analyst@example.com")
+    ///     .with_description("confirmed stolen card")
+    ///     .with_time(UNIX_EPOCH + Duration::from_millis(1461963439151));
+    ///
+    /// assert!(matches!(decision.source, Source::ManualReview));
+    /// assert_eq!(decision.analyst.as_deref(), Some("analyst@example.com"));
+    /// assert!(decision.validate().is_ok());
+    ///
+    /// let value = serde_json::to_value(&decision).unwrap();
+    /// assert_eq!(value["source"], "MANUAL_REVIEW");
+    /// assert_eq!(value["analyst"], "analyst@example.com");
+    /// assert_eq!(value["description"], "confirmed stolen card");
+    /// assert_eq!(value["time"], 1461963439151u64);
+    /// ```
+    pub fn manual_review(decision_id: impl Into<String>, analyst: impl Into<String>) -> Self {
+        DecisionRequest {
+            analyst: Some(analyst.into()),
+            ..DecisionRequest::new(decision_id, Source::ManualReview)
+        }
+    }
+
+    /// A decision applied by an automated rules engine or internal system.
+    ///
+    /// ```
+    /// use sift_science::decisions::{DecisionRequest, Source};
+    ///
+    /// let decision = DecisionRequest::automated("block_user_payment_abuse");
+    /// assert!(matches!(decision.source, Source::AutomatedRule));
+    /// assert!(decision.analyst.is_none());
+    /// assert!(decision.validate().is_ok());
+    ///
+    /// let decision = DecisionRequest::chargeback("block_user_payment_abuse");
+    /// assert!(matches!(decision.source, Source::Chargeback));
+    /// assert!(decision.analyst.is_none());
+    /// ```
+    pub fn automated(decision_id: impl Into<String>) -> Self {
+        DecisionRequest::new(decision_id, Source::AutomatedRule)
+    }
+
+    /// A decision applied automatically in response to a chargeback.
+    pub fn chargeback(decision_id: impl Into<String>) -> Self {
+        DecisionRequest::new(decision_id, Source::Chargeback)
+    }
+
+    fn new(decision_id: impl Into<String>, source: Source) -> Self {
+        DecisionRequest {
+            decision_id: decision_id.into(),
+            source,
+            analyst: None,
+            time: None,
+            description: None,
+        }
+    }
+
+    /// Sets the description of the decision.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the time the decision was applied, for historical backfill.
+    pub fn with_time(mut self, time: SystemTime) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Checks that an analyst is set for [Source::ManualReview] decisions.
+    ///
+    /// Called by [Client::apply_decision] before the request is sent.
+    ///
+    /// ```
+    /// use sift_science::{
+    ///     decisions::{DecisionRequest, Source},
+    ///     Error,
+    /// };
+    ///
+    /// let decision = DecisionRequest {
+    ///     decision_id: "block_user_payment_abuse".into(),
+    ///     source: Source::ManualReview,
+    ///     analyst: None,
+    ///     time: None,
+    ///     description: None,
+    /// };
+    /// assert!(matches!(decision.validate(), Err(Error::Validation(_))));
+    /// ```
+    ///
+    /// [Client::apply_decision]: crate::Client::apply_decision
+    pub fn validate(&self) -> Result<()> {
+        if matches!(self.source, Source::ManualReview) && self.analyst.is_none() {
+            return Err(Error::Validation(
+                "analyst is required for MANUAL_REVIEW decisions".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// The source of a sift [Decision].
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -161,6 +380,7 @@ pub enum Source {
 
 /// The Sift response to decisions
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct Decision {
     /// The decision entity
     pub entity: EntityIdentifier,
@@ -175,6 +395,7 @@ pub struct Decision {
 
 /// An entity is identified by a type and an id
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct EntityIdentifier {
     /// The type of entity on which the decision was taken.
     #[serde(rename = "type")]
@@ -186,6 +407,7 @@ pub struct EntityIdentifier {
 
 /// The status of a decision
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DecisionStatus {
     /// The latest decision
     pub decisions: Decisions,
@@ -193,11 +415,13 @@ pub struct DecisionStatus {
 
 /// The decisions for a given entity
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct Decisions {
     /// Latest payment abuse decision
     pub payment_abuse: Option<LatestDecision>,
 
     /// Latest promo abuse decision
+    #[serde(rename = "promotion_abuse", alias = "promo_abuse")]
     pub promo_abuse: Option<LatestDecision>,
 
     /// Latest content abuse decision
@@ -215,6 +439,7 @@ pub struct Decisions {
 
 /// The latest decision for an abuse type
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct LatestDecision {
     /// Latest legacy decision
     pub decision: DecisionIdentifier,
@@ -232,6 +457,7 @@ pub struct LatestDecision {
 
 /// The latest decision reference
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DecisionIdentifier {
     /// The decision's id
     pub id: String,
@@ -239,6 +465,7 @@ pub struct DecisionIdentifier {
 
 /// A page of decisions
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DecisionPage {
     /// Decisions in this page
     #[serde(rename = "data")]
@@ -254,8 +481,48 @@ pub struct DecisionPage {
     pub total_results: u32,
 }
 
+impl Page for DecisionPage {
+    type Item = DecisionData;
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    fn into_items(self) -> Vec<DecisionData> {
+        self.decisions
+    }
+}
+
+/// Optional parameters for listing decisions.
+///
+/// The Decisions API takes the abuse types as a single comma separated `abuse_types` param, the
+/// same as the Score API.
+#[derive(Debug, Default, Clone)]
+pub struct ListDecisionsOptions {
+    /// Filter results to a single entity type.
+    pub entity_type: Option<EntityType>,
+
+    /// Filter results to the given abuse types, sent comma separated.
+    pub abuse_types: Option<Vec<AbuseType>>,
+
+    /// The offset of the first decision to return.
+    ///
+    /// Used in conjunction with `limit` to page through result sets.
+    pub from: Option<u32>,
+
+    /// The maximum number of decisions to return.
+    pub limit: Option<u32>,
+
+    /// Overrides the timeout for this call.
+    pub timeout: Option<Duration>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
+}
+
 /// The data for paginated decisions
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DecisionData {
     /// The id of the decision.
     ///
@@ -317,9 +584,13 @@ pub(crate) enum DecisionResult<T> {
 }
 
 /// Decisions API version
-#[derive(Copy, Clone, Debug)]
+///
+/// Defaults to the latest version supported by this library.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ApiVersion {
     /// Version 3
+    #[default]
     V3,
 }
 
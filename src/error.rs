@@ -1,4 +1,6 @@
 use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Sift result type
@@ -32,7 +34,7 @@ pub enum Error {
         /// Non-zero indicates error status
         ///
         /// Docs <https://sift.com/developers/docs/curl/events-api/error-codes>
-        status: i32,
+        status: SiftErrorCode,
 
         /// Error message
         ///
@@ -41,12 +43,328 @@ pub enum Error {
     },
 
     /// Server errors
+    ///
+    /// Unexpected responses, such as a missing response body, and client-side failures like a
+    /// missing account id. These are not retried.
     #[error("Sift server error: {0}")]
     Server(String),
+
+    /// HTTP errors, for non-2xx responses without a Sift error in the body
+    ///
+    /// e.g. an HTML error page from a gateway. Carries the HTTP status and the raw body, and is
+    /// retryable for `5xx` statuses.
+    #[error("Sift HTTP error {status}: {body}")]
+    #[serde(skip)]
+    Http {
+        /// The HTTP status code
+        status: u16,
+
+        /// The response body
+        body: String,
+    },
+
+    /// Validation errors, raised before a request is sent
+    #[error("Sift validation error: {0}")]
+    #[serde(skip)]
+    Validation(String),
+
+    /// Rate limiting, reported by Sift with an HTTP `429 Too Many Requests` response
+    ///
+    /// Retried by the [RetryPolicy], waiting for `retry_after` when Sift sent one, up to the
+    /// policy's `max_delay`.
+    ///
+    /// [RetryPolicy]: crate::RetryPolicy
+    #[error("Sift rate limit exceeded")]
+    #[serde(skip)]
+    RateLimited {
+        /// How long Sift asked to wait before retrying, from the `Retry-After` header.
+        ///
+        /// Only the delay-seconds form of the header is supported.
+        retry_after: Option<Duration>,
+    },
+
+    /// Webhook signature errors, raised when an inbound webhook is missing a valid signature
+    #[error("Sift webhook signature error: {0}")]
+    #[serde(skip)]
+    InvalidSignature(String),
+
+    /// Transport errors, e.g. connection failures or timeouts
+    #[error("Sift transport error: {0}")]
+    #[serde(skip)]
+    Transport(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// Timeouts, when Sift did not respond within the request timeout
+    #[error("Sift request timed out: {0}")]
+    #[serde(skip)]
+    Timeout(#[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// Errors deserializing a Sift response
+    #[error("Sift deserialization error: {0}")]
+    #[serde(skip)]
+    Deserialize(#[from] serde_json::Error),
+}
+
+impl Error {
+    /// Whether the error is transient, and the request may succeed if retried.
+    ///
+    /// Transport errors, timeouts, `5xx` responses, rate limiting and transient [SiftErrorCode]s
+    /// are retryable. Other HTTP errors, and [Error::Server] errors raised by the client itself,
+    /// are not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Transport(_) | Error::Timeout(_) | Error::RateLimited { .. } => true,
+            Error::Http { status, .. } => *status >= 500,
+            Error::Request { status, .. } => status.is_retryable(),
+            Error::Server(_)
+            | Error::Client { .. }
+            | Error::Validation(_)
+            | Error::InvalidSignature(_)
+            | Error::Deserialize(_) => false,
+        }
+    }
+
+    #[cfg(any(
+        feature = "awc",
+        feature = "awc3",
+        feature = "hyper",
+        feature = "reqwest",
+        feature = "surf",
+        feature = "ureq"
+    ))]
+    pub(crate) fn transport(err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Error::Transport(err.into())
+    }
+
+    #[cfg(any(feature = "hyper", feature = "reqwest", feature = "surf"))]
+    pub(crate) fn timeout(err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Error::Timeout(err.into())
+    }
+
+    // The Sift error in a non-2xx response body, or an HTTP error with the raw body when it
+    // isn't one, e.g. an HTML error page from a gateway
+    #[cfg(any(
+        feature = "awc",
+        feature = "awc3",
+        feature = "hyper",
+        feature = "reqwest",
+        feature = "surf",
+        feature = "ureq"
+    ))]
+    pub(crate) fn from_response(status: u16, body: &[u8]) -> Self {
+        serde_json::from_slice(body).unwrap_or_else(|_| Error::Http {
+            status,
+            body: String::from_utf8_lossy(body).trim().to_string(),
+        })
+    }
+
+    // A rate limit error, with the delay from the `Retry-After` header
+    #[cfg(any(
+        feature = "awc",
+        feature = "awc3",
+        feature = "hyper",
+        feature = "reqwest",
+        feature = "surf",
+        feature = "ureq"
+    ))]
+    pub(crate) fn rate_limited(retry_after: Option<&str>) -> Self {
+        Error::RateLimited {
+            retry_after: retry_after
+                .and_then(|seconds| seconds.trim().parse().ok())
+                .map(Duration::from_secs),
+        }
+    }
+}
+
+/// Timeouts map to [Error::Timeout] and `429 Too Many Requests` to [Error::RateLimited]. Other
+/// failures, such as connection or body decoding errors, are [Error::Transport] errors with the
+/// `reqwest` error as their source.
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Error::timeout(err)
+        } else if err.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) {
+            Error::RateLimited { retry_after: None }
+        } else {
+            Error::transport(err)
+        }
+    }
+}
+
+/// `reqwest` errors convert as they do without middleware, and middleware errors are
+/// [Error::Transport] errors.
+#[cfg(feature = "reqwest-middleware")]
+impl From<reqwest_middleware::Error> for Error {
+    fn from(err: reqwest_middleware::Error) -> Self {
+        match err {
+            reqwest_middleware::Error::Reqwest(err) => err.into(),
+            err => Error::transport(err),
+        }
+    }
+}
+
+/// Timeouts map to [Error::Timeout], and other failures to [Error::Transport]. `awc` errors are
+/// not `Send`, so only their message is kept.
+#[cfg(feature = "awc")]
+impl From<awc::error::SendRequestError> for Error {
+    fn from(err: awc::error::SendRequestError) -> Self {
+        match err {
+            awc::error::SendRequestError::Timeout => Error::Timeout(err.to_string().into()),
+            err => Error::Transport(err.to_string().into()),
+        }
+    }
+}
+
+/// Timeouts map to [Error::Timeout], and other failures to [Error::Transport]. `awc` errors are
+/// not `Send`, so only their message is kept.
+#[cfg(feature = "awc3")]
+impl From<awc3::error::SendRequestError> for Error {
+    fn from(err: awc3::error::SendRequestError) -> Self {
+        match err {
+            awc3::error::SendRequestError::Timeout => Error::Timeout(err.to_string().into()),
+            err => Error::Transport(err.to_string().into()),
+        }
+    }
+}
+
+/// Sift API status codes.
+///
+/// See <https://sift.com/developers/docs/curl/events-api/error-codes>
+///
+/// ```
+/// use sift_science::SiftErrorCode;
+///
+/// assert_eq!(SiftErrorCode::from_i32(0), SiftErrorCode::Success);
+/// assert_eq!(SiftErrorCode::from_i32(51), SiftErrorCode::InvalidApiKey);
+/// assert_eq!(SiftErrorCode::from_i32(60), SiftErrorCode::RateLimited);
+/// assert_eq!(SiftErrorCode::from_i32(-3), SiftErrorCode::ServerTimeout);
+/// assert_eq!(SiftErrorCode::from_i32(999), SiftErrorCode::Unknown(999));
+/// assert_eq!(SiftErrorCode::from_i32(104).as_i32(), 104);
+///
+/// assert!(SiftErrorCode::RateLimited.is_retryable());
+/// assert!(SiftErrorCode::ServiceUnavailable.is_retryable());
+/// assert!(!SiftErrorCode::InvalidApiKey.is_retryable());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "i32")]
+pub enum SiftErrorCode {
+    /// Service currently unavailable. Please try again later.
+    ServiceUnavailable,
+
+    /// Server-side timeout processing request. Please try again later.
+    ServerTimeout,
+
+    /// Unexpected server-side error (-2).
+    UnexpectedServerError,
+
+    /// Unexpected server-side error (-1).
+    InternalServerError,
+
+    /// Success.
+    Success,
+
+    /// Invalid API key.
+    InvalidApiKey,
+
+    /// Invalid characters in field name.
+    InvalidFieldName,
+
+    /// Invalid characters in field value.
+    InvalidFieldValue,
+
+    /// Specified user_id has no scoreable events.
+    NoScoreableEvents,
+
+    /// Missing required field.
+    MissingRequiredField,
+
+    /// Invalid JSON in request.
+    InvalidJson,
+
+    /// Invalid HTTP body.
+    InvalidHttpBody,
+
+    /// Rate limited; too many events have been received in a short period of time.
+    RateLimited,
+
+    /// Invalid API version.
+    InvalidApiVersion,
+
+    /// Not a valid reserved field.
+    InvalidReservedField,
+
+    /// A status code not documented by Sift.
+    Unknown(i32),
+}
+
+impl SiftErrorCode {
+    /// Map a numeric Sift status code.
+    pub fn from_i32(code: i32) -> Self {
+        match code {
+            -4 => SiftErrorCode::ServiceUnavailable,
+            -3 => SiftErrorCode::ServerTimeout,
+            -2 => SiftErrorCode::UnexpectedServerError,
+            -1 => SiftErrorCode::InternalServerError,
+            0 => SiftErrorCode::Success,
+            51 => SiftErrorCode::InvalidApiKey,
+            52 => SiftErrorCode::InvalidFieldName,
+            53 => SiftErrorCode::InvalidFieldValue,
+            54 => SiftErrorCode::NoScoreableEvents,
+            55 => SiftErrorCode::MissingRequiredField,
+            56 => SiftErrorCode::InvalidJson,
+            57 => SiftErrorCode::InvalidHttpBody,
+            60 => SiftErrorCode::RateLimited,
+            104 => SiftErrorCode::InvalidApiVersion,
+            105 => SiftErrorCode::InvalidReservedField,
+            code => SiftErrorCode::Unknown(code),
+        }
+    }
+
+    /// The numeric Sift status code.
+    pub fn as_i32(&self) -> i32 {
+        match self {
+            SiftErrorCode::ServiceUnavailable => -4,
+            SiftErrorCode::ServerTimeout => -3,
+            SiftErrorCode::UnexpectedServerError => -2,
+            SiftErrorCode::InternalServerError => -1,
+            SiftErrorCode::Success => 0,
+            SiftErrorCode::InvalidApiKey => 51,
+            SiftErrorCode::InvalidFieldName => 52,
+            SiftErrorCode::InvalidFieldValue => 53,
+            SiftErrorCode::NoScoreableEvents => 54,
+            SiftErrorCode::MissingRequiredField => 55,
+            SiftErrorCode::InvalidJson => 56,
+            SiftErrorCode::InvalidHttpBody => 57,
+            SiftErrorCode::RateLimited => 60,
+            SiftErrorCode::InvalidApiVersion => 104,
+            SiftErrorCode::InvalidReservedField => 105,
+            SiftErrorCode::Unknown(code) => *code,
+        }
+    }
+
+    /// Whether the code indicates a transient failure, i.e. a server-side error or rate limiting.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SiftErrorCode::RateLimited => true,
+            code => code.as_i32() < 0,
+        }
+    }
+}
+
+impl From<i32> for SiftErrorCode {
+    fn from(code: i32) -> Self {
+        SiftErrorCode::from_i32(code)
+    }
+}
+
+impl From<SiftErrorCode> for i32 {
+    fn from(code: SiftErrorCode) -> Self {
+        code.as_i32()
+    }
 }
 
-impl From<serde_json::Error> for Error {
-    fn from(err: serde_json::Error) -> Self {
-        Error::Server(err.to_string())
+impl fmt::Display for SiftErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_i32())
     }
 }
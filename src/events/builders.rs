@@ -0,0 +1,325 @@
+//! Builders for the larger event property structs.
+//!
+//! Each builder produces the same property struct as the struct-literal form, so the two can be
+//! used interchangeably.
+//!
+//! ```
+//! use sift_science::events::{
+//!     AccountType, CreateAccountProperties, LoginProperties, SocialSignOn, TransactionProperties,
+//!     TransactionStatus, TransactionType,
+//! };
+//!
+//! let built = CreateAccountProperties::builder()
+//!     .user_email("bill@gmail.com")
+//!     .phone("1-415-555-6040")
+//!     .social_sign_on_type(SocialSignOn::Google)
+//!     .build();
+//!
+//! let literal = CreateAccountProperties {
+//!     user_email: Some("bill@gmail.com".into()),
+//!     phone: Some("1-415-555-6040".into()),
+//!     social_sign_on_type: Some(SocialSignOn::Google),
+//!     ..Default::default()
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(&built).unwrap(),
+//!     serde_json::to_value(&literal).unwrap()
+//! );
+//!
+//! let built = LoginProperties::builder()
+//!     .account_types(["merchant", "premium"])
+//!     .build();
+//!
+//! assert_eq!(
+//!     built.account_types,
+//!     Some(vec![AccountType::Merchant, AccountType::Premium])
+//! );
+//! assert_eq!(
+//!     serde_json::to_value(&built).unwrap()["$account_types"],
+//!     serde_json::json!(["merchant", "premium"])
+//! );
+//!
+//! # #[cfg(feature = "phonenumber")]
+//! # {
+//! let built = CreateAccountProperties::builder()
+//!     .phone_e164("1-415-555-6040", "US")
+//!     .build();
+//! assert_eq!(built.phone.as_deref(), Some("+14155556040"));
+//!
+//! let built = CreateAccountProperties::builder()
+//!     .phone_e164("ext. 12", "US")
+//!     .build();
+//! assert_eq!(built.phone.as_deref(), Some("ext. 12"));
+//! # }
+//!
+//! let built = TransactionProperties::builder()
+//!     .transaction_type(TransactionType::Sale)
+//!     .transaction_status(TransactionStatus::Failure)
+//!     .order_id("ORDER-28168441")
+//!     .build();
+//!
+//! let literal = TransactionProperties {
+//!     transaction_type: Some(TransactionType::Sale),
+//!     transaction_status: Some(TransactionStatus::Failure),
+//!     order_id: Some("ORDER-28168441".into()),
+//!     ..Default::default()
+//! };
+//!
+//! assert_eq!(
+//!     serde_json::to_value(&built).unwrap(),
+//!     serde_json::to_value(&literal).unwrap()
+//! );
+//! ```
+
+#[cfg(feature = "phonenumber")]
+use crate::events::phone::normalize_phone_or_raw;
+use crate::events::{
+    complex_field_types::{
+        Address, App, Booking, Browser, DigitalOrder, Item, MerchantProfile, OrderedFrom,
+        PaymentMethod, Promotion,
+    },
+    reserved_events::{
+        CreateAccountProperties, LoginProperties, OrderProperties, TransactionProperties,
+    },
+    reserved_fields::*,
+    CurrencyCode, Micros, Money,
+};
+
+macro_rules! properties_builder {
+    (
+        $properties:ident => $builder:ident {
+            strings { $($string:ident),* $(,)? }
+            options { $($option:ident: $option_ty:ty),* $(,)? }
+            lists { $($list:ident: $list_ty:ty),* $(,)? }
+            phones { $($phone_setter:ident => $phone:ident),* $(,)? }
+        }
+    ) => {
+        #[doc = concat!("Builder for [", stringify!($properties), "].")]
+        #[derive(Debug, Default)]
+        pub struct $builder {
+            properties: $properties,
+        }
+
+        impl $properties {
+            #[doc = concat!("Create a new [", stringify!($builder), "].")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        impl $builder {
+            $(
+                #[doc = concat!("Sets [", stringify!($properties), "::", stringify!($string), "].")]
+                #[must_use]
+                pub fn $string(mut self, $string: impl Into<String>) -> Self {
+                    self.properties.$string = Some($string.into());
+                    self
+                }
+            )*
+
+            $(
+                #[doc = concat!("Sets [", stringify!($properties), "::", stringify!($option), "].")]
+                #[must_use]
+                pub fn $option(mut self, $option: impl Into<$option_ty>) -> Self {
+                    self.properties.$option = Some($option.into());
+                    self
+                }
+            )*
+
+            $(
+                #[doc = concat!("Sets [", stringify!($properties), "::", stringify!($list), "].")]
+                #[must_use]
+                pub fn $list<I>(mut self, $list: I) -> Self
+                where
+                    I: IntoIterator,
+                    I::Item: Into<$list_ty>,
+                {
+                    self.properties.$list = Some($list.into_iter().map(Into::into).collect());
+                    self
+                }
+            )*
+
+            $(
+                #[doc = concat!(
+                    "Sets [", stringify!($properties), "::", stringify!($phone), "], normalized to ",
+                    "E.164 using `default_region` for national numbers.\n\n",
+                    "Numbers that can't be normalized are set as is. See [normalize_phone].\n\n",
+                    "Only available with the `phonenumber` feature.\n\n",
+                    "[normalize_phone]: crate::events::normalize_phone",
+                )]
+                #[must_use]
+                #[cfg(feature = "phonenumber")]
+                pub fn $phone_setter(mut self, $phone: impl Into<String>, default_region: &str) -> Self {
+                    self.properties.$phone =
+                        Some(normalize_phone_or_raw($phone.into(), default_region));
+                    self
+                }
+            )*
+
+            #[doc = concat!("Build the [", stringify!($properties), "].")]
+            #[must_use]
+            pub fn build(self) -> $properties {
+                self.properties
+            }
+        }
+
+        impl From<$builder> for $properties {
+            fn from(builder: $builder) -> Self {
+                builder.build()
+            }
+        }
+    };
+}
+
+properties_builder!(CreateAccountProperties => CreateAccountPropertiesBuilder {
+    strings {
+        user_email,
+        name,
+        phone,
+        ip,
+        referrer_user_id,
+        brand_name,
+        site_country,
+        site_domain,
+    }
+    options {
+        payment_methods: Vec<PaymentMethod>,
+        billing_address: Address,
+        shipping_address: Address,
+        promotions: Vec<Promotion>,
+        social_sign_on_type: SocialSignOn,
+        browser: Browser,
+        app: App,
+        extra: serde_json::Value,
+    }
+    lists {
+        account_types: AccountType,
+    }
+    phones {
+        phone_e164 => phone,
+    }
+});
+
+properties_builder!(OrderProperties => OrderPropertiesBuilder {
+    strings {
+        session_id,
+        order_id,
+        user_email,
+        verification_phone_number,
+        ip,
+        seller_user_id,
+        shipping_carrier,
+        brand_name,
+        site_country,
+        site_domain,
+    }
+    options {
+        amount: Micros,
+        currency_code: CurrencyCode,
+        billing_address: Address,
+        payment_methods: Vec<PaymentMethod>,
+        shipping_address: Address,
+        expedited_shipping: bool,
+        items: Vec<Item>,
+        bookings: Vec<Booking>,
+        promotions: Vec<Promotion>,
+        shipping_method: ShippingMethod,
+        shipping_tracking_numbers: Vec<String>,
+        ordered_from: OrderedFrom,
+        merchant_profile: MerchantProfile,
+        digital_orders: Vec<DigitalOrder>,
+        extra: serde_json::Value,
+    }
+    lists {}
+    phones {
+        verification_phone_number_e164 => verification_phone_number,
+    }
+});
+
+impl OrderPropertiesBuilder {
+    /// Sets [OrderProperties::amount] and [OrderProperties::currency_code] together.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::events::{Micros, Money, OrderProperties};
+    ///
+    /// let built = OrderProperties::builder()
+    ///     .total(Money::new(Micros::from_base_units(11_500), "USD"))
+    ///     .build();
+    /// assert_eq!(
+    ///     serde_json::to_value(&built).unwrap(),
+    ///     json!({ "$amount": 115_000_000, "$currency_code": "USD" })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn total(mut self, total: Money) -> Self {
+        self.properties.amount = Some(total.amount);
+        self.properties.currency_code = Some(total.currency);
+        self
+    }
+}
+
+properties_builder!(LoginProperties => LoginPropertiesBuilder {
+    strings {
+        user_email,
+        ip,
+        username,
+        brand_name,
+        site_country,
+        site_domain,
+    }
+    options {
+        login_status: LoginStatus,
+        browser: Browser,
+        app: App,
+        failure_reason: LoginFailureReason,
+        social_sign_on_type: SocialSignOn,
+        extra: serde_json::Value,
+    }
+    lists {
+        account_types: AccountType,
+    }
+    phones {}
+});
+
+properties_builder!(TransactionProperties => TransactionPropertiesBuilder {
+    strings {
+        user_email,
+        verification_phone_number,
+        ip,
+        order_id,
+        transaction_id,
+        session_id,
+        seller_user_id,
+        transfer_recipient_user_id,
+        brand_name,
+        site_country,
+        site_domain,
+        receiver_wallet_address,
+    }
+    options {
+        transaction_type: TransactionType,
+        transaction_status: TransactionStatus,
+        billing_address: Address,
+        payment_method: PaymentMethod,
+        shipping_address: Address,
+        decline_category: DeclineCategory,
+        ordered_from: OrderedFrom,
+        browser: Browser,
+        app: App,
+        status_3ds: Status3Ds,
+        triggered_3ds: Triggered3Ds,
+        merchant_initiated_transaction: bool,
+        merchant_profile: MerchantProfile,
+        sent_address: Address,
+        received_address: Address,
+        receiver_external_address: bool,
+        digital_orders: Vec<DigitalOrder>,
+        extra: serde_json::Value,
+    }
+    lists {}
+    phones {
+        verification_phone_number_e164 => verification_phone_number,
+    }
+});
@@ -1,5 +1,9 @@
-use crate::common::{deserialize_opt_ms, serialize_opt_ms};
-use crate::events::{Micros, PaymentMethodVerificationStatus, PaymentType};
+use crate::common::{deserialize_extra, deserialize_opt_ms, serialize_opt_ms};
+use crate::events::{
+    CurrencyCode, DeclineReason, DigitalWallet, Micros, Money, PaymentMethodVerificationStatus,
+    PaymentType,
+};
+use crate::{Decimal, Error, Result};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::time::SystemTime;
@@ -9,7 +13,7 @@ use std::time::SystemTime;
 /// The value must be a nested object with the appropriate address subfields. We extract many
 /// geolocation features from these values. An address is represented as a nested JSON object.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Address {
     /// Provide the full name associated with the address here.
     ///
@@ -63,13 +67,94 @@ pub struct Address {
     pub phone: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the address.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
+impl Address {
+    /// Set the [ISO-3166] alpha-2 country code, uppercasing it.
+    ///
+    /// Codes that are not two letters are rejected with [Error::Validation]. An empty code leaves
+    /// the country unset.
+    ///
+    /// ```
+    /// use sift_science::{events::Address, Error};
+    ///
+    /// let address = Address::default().with_country("us").unwrap();
+    /// assert_eq!(address.country.as_deref(), Some("US"));
+    ///
+    /// let address = Address::default().with_country("").unwrap();
+    /// assert_eq!(address.country, None);
+    ///
+    /// assert!(matches!(
+    ///     Address::default().with_country("USA"),
+    ///     Err(Error::Validation(_))
+    /// ));
+    /// ```
+    ///
+    /// [ISO-3166]: http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+    pub fn with_country(mut self, code: impl AsRef<str>) -> Result<Self> {
+        self.country = country_code(code.as_ref())?;
+        Ok(self)
+    }
+
+    /// Trim and uppercase the country code and short region codes, e.g. "ca" for California.
+    ///
+    /// Longer region names, like "California", are left as is. The country code is validated as in
+    /// [Address::with_country].
+    ///
+    /// ```
+    /// use sift_science::events::Address;
+    ///
+    /// let address = Address {
+    ///     region: Some("ca".into()),
+    ///     country: Some(" us ".into()),
+    ///     ..Default::default()
+    /// }
+    /// .normalize()
+    /// .unwrap();
+    ///
+    /// assert_eq!(address.region.as_deref(), Some("CA"));
+    /// assert_eq!(address.country.as_deref(), Some("US"));
+    /// ```
+    pub fn normalize(mut self) -> Result<Self> {
+        if let Some(country) = self.country.take() {
+            self.country = country_code(&country)?;
+        }
+
+        if let Some(region) = &mut self.region {
+            let trimmed = region.trim();
+            *region = if trimmed.len() <= 3 && trimmed.chars().all(|c| c.is_ascii_alphanumeric()) {
+                trimmed.to_ascii_uppercase()
+            } else {
+                trimmed.to_string()
+            };
+        }
+
+        Ok(self)
+    }
+}
+
+// Validate and uppercase an ISO-3166 alpha-2 country code, treating an empty code as unset
+fn country_code(code: &str) -> Result<Option<String>> {
+    let code = code.trim();
+    if code.is_empty() {
+        return Ok(None);
+    }
+
+    if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(Error::Validation(format!(
+            "{:?} is not an ISO-3166 alpha-2 country code",
+            code
+        )));
+    }
+
+    Ok(Some(code.to_ascii_uppercase()))
+}
+
 /// The details of an application as well as the device and OS it's running on.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct App {
     /// The operating system on which application is running.
     ///
@@ -121,7 +206,7 @@ pub struct App {
     pub client_language: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the app.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -140,8 +225,46 @@ pub struct App {
 /// sending both `Booking::Flight` and `Booking::Accommodation` bookings in one order.
 ///
 /// Please not that `Booking` does not accept custom fields.
+///
+/// ```
+/// use serde_json::json;
+/// use sift_science::events::Booking;
+///
+/// for booking_type in [
+///     "$event_ticket",
+///     "$accommodation",
+///     "$flight",
+///     "$bus",
+///     "$rideshare",
+///     "$vehicle",
+///     "$cruise",
+///     "$other",
+/// ] {
+///     let json = json!({
+///         "$booking_type": booking_type,
+///         "$title": "Summer getaway",
+///         "$tags": ["summer sale"],
+///     });
+///
+///     let booking: Booking = serde_json::from_value(json.clone()).unwrap();
+///     let tags = match &booking {
+///         Booking::EventTicket { tags, .. }
+///         | Booking::Accomodation { tags, .. }
+///         | Booking::Flight { tags, .. }
+///         | Booking::Bus { tags, .. }
+///         | Booking::Rideshare { tags, .. }
+///         | Booking::Vehicle { tags, .. }
+///         | Booking::Cruise { tags, .. }
+///         | Booking::Other { tags, .. } => tags.clone(),
+///         _ => unreachable!(),
+///     };
+///
+///     assert_eq!(tags, Some(vec!["summer sale".to_string()]), "{}", booking_type);
+///     assert_eq!(serde_json::to_value(&booking).unwrap(), json);
+/// }
+/// ```
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 #[serde(tag = "$booking_type")]
 pub enum Booking {
@@ -155,6 +278,7 @@ pub enum Booking {
         /// The start time of the event.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -163,6 +287,7 @@ pub enum Booking {
         /// The finish time of the event.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -179,7 +304,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of reservations of the given type purchased by the user.
         ///
@@ -217,7 +342,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the event.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -233,6 +358,7 @@ pub enum Booking {
         /// The check-in time for a hotel reservation.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -241,6 +367,7 @@ pub enum Booking {
         /// The check-out time for a hotel reservation.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -257,7 +384,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of reservations.
         ///
@@ -292,7 +419,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the accomodatio.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -306,6 +433,7 @@ pub enum Booking {
         /// The departure time for the first flight leg in the booking.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -314,6 +442,7 @@ pub enum Booking {
         /// The arrival time for the last flight leg in the booking etc.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -330,7 +459,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of tickets of the given type purchased by the user.
         #[serde(rename = "$quantity")]
@@ -354,7 +483,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the flight.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -368,6 +497,7 @@ pub enum Booking {
         /// The departure time for a trip.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -376,6 +506,7 @@ pub enum Booking {
         /// The arrival time of the trip.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -392,7 +523,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of tickets of the given type purchased by the user.
         #[serde(rename = "$quantity")]
@@ -417,7 +548,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the bus.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -431,6 +562,7 @@ pub enum Booking {
         /// The pickup time of the ride.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -439,6 +571,7 @@ pub enum Booking {
         /// The estimated drop-off time of the ride.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -455,7 +588,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of reservations of the given type purchased by the user.
         ///
@@ -481,7 +614,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the ride share.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -495,6 +628,7 @@ pub enum Booking {
         /// The pickup time for the reservation.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -503,6 +637,7 @@ pub enum Booking {
         /// The drop-off time of the reservation.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -519,7 +654,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of reservations of the given type purchased by the user.
         ///
@@ -545,7 +680,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the vehicle.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -559,6 +694,7 @@ pub enum Booking {
         /// The departure time of the cruise.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -567,6 +703,7 @@ pub enum Booking {
         /// The arrival time of the cruise.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -583,7 +720,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of tickets of the given type purchased by the user.
         #[serde(rename = "$quantity")]
@@ -609,7 +746,7 @@ pub enum Booking {
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the cruise.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 
@@ -623,6 +760,7 @@ pub enum Booking {
         /// The start time of the reservation.
         #[serde(
             rename = "$start_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -631,6 +769,7 @@ pub enum Booking {
         /// The finish time of the reservation.
         #[serde(
             rename = "$end_time",
+            default,
             deserialize_with = "deserialize_opt_ms",
             serialize_with = "serialize_opt_ms"
         )]
@@ -647,7 +786,7 @@ pub enum Booking {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: Option<String>,
+        currency_code: Option<CurrencyCode>,
 
         /// The count of reservations of the given type purchased by the user.
         #[serde(rename = "$quantity")]
@@ -695,17 +834,86 @@ pub enum Booking {
         /// This field captures any descriptors of the events.
         ///
         /// For event tickets, for example, tags might be team names, region, etc.
+        #[serde(rename = "$tags")]
         tags: Option<Vec<String>>,
 
         /// Any extra non-reserved fields to be recorded with the booking.
-        #[serde(flatten)]
+        #[serde(flatten, deserialize_with = "deserialize_extra")]
         extra: Option<serde_json::Value>,
     },
 }
 
+impl Booking {
+    /// Set the booking's price and its currency.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::events::{Booking, Micros, Money};
+    ///
+    /// let booking: Booking = serde_json::from_value(json!({ "$booking_type": "$flight" })).unwrap();
+    /// let booking = booking.with_price(Money::new(Micros::from_base_units(49_900), "EUR"));
+    /// assert_eq!(
+    ///     serde_json::to_value(&booking).unwrap(),
+    ///     json!({
+    ///         "$booking_type": "$flight",
+    ///         "$price": 499_000_000,
+    ///         "$currency_code": "EUR",
+    ///     })
+    /// );
+    /// ```
+    pub fn with_price(mut self, money: Money) -> Self {
+        match &mut self {
+            Booking::EventTicket {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Accomodation {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Flight {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Bus {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Rideshare {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Vehicle {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Cruise {
+                price,
+                currency_code,
+                ..
+            }
+            | Booking::Other {
+                price,
+                currency_code,
+                ..
+            } => {
+                *price = Some(money.amount);
+                *currency_code = Some(money.currency);
+            }
+        }
+        self
+    }
+}
+
 /// Information about the user's web browser.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Browser {
     /// The user agent of the browser that is interacting with your website.
     #[serde(rename = "$user_agent")]
@@ -730,10 +938,42 @@ pub struct Browser {
     pub content_language: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the browser.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
+impl Browser {
+    /// A browser known only by its raw `User-Agent` header.
+    ///
+    /// Use the struct form to also record the accept or content language.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::events::{Browser, LoginProperties};
+    ///
+    /// let user_agent = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_11_1) AppleWebKit/537.36";
+    /// assert_eq!(
+    ///     serde_json::to_value(Browser::from_user_agent(user_agent)).unwrap(),
+    ///     json!({ "$user_agent": user_agent })
+    /// );
+    ///
+    /// // Property structs with a browser can set it directly
+    /// let properties = LoginProperties::default().with_browser_ua(user_agent);
+    /// assert_eq!(
+    ///     serde_json::to_value(&properties).unwrap()["$browser"],
+    ///     json!({ "$user_agent": user_agent })
+    /// );
+    /// ```
+    pub fn from_user_agent(user_agent: impl Into<String>) -> Self {
+        Browser {
+            user_agent: user_agent.into(),
+            accept_language: None,
+            content_language: None,
+            extra: None,
+        }
+    }
+}
+
 /// Monetary and non-monetary rewards.
 ///
 /// (e.g. in-game currency, stored account value, MBs storage, frequent flyer miles, etc)
@@ -743,7 +983,7 @@ pub struct Browser {
 ///
 /// A `credit_point` is an object that gets included as part of promotion object. Learn more about
 /// [Promotion]s.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreditPoint {
     /// The amount of credits the promotion is worth.
     #[serde(rename = "$amount")]
@@ -756,7 +996,7 @@ pub struct CreditPoint {
     pub credit_point_type: String,
 
     /// Any extra non-reserved fields to be recorded with the credit point.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -772,6 +1012,7 @@ pub struct CreditPoint {
 /// ## Examples
 ///
 /// ```
+/// use serde_json::json;
 /// use sift_science::events::{
 ///     DigitalOrder, DigitalOrderAssetType, DigitalOrderType,
 /// };
@@ -782,15 +1023,42 @@ pub struct CreditPoint {
 ///     asset_type: Some(DigitalOrderAssetType::Crypto),
 ///     order_type: Some(DigitalOrderType::Market),
 ///     volume: Some("6.0".to_string()),
+///     extra: Some(json!({ "exchange": "coinbase" })),
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&order).unwrap(),
+///     json!({
+///         "$digital_asset": "BTC",
+///         "$pair": "BTC_USD",
+///         "$asset_type": "$crypto",
+///         "$order_type": "$market",
+///         "$volume": "6.0",
+///         "exchange": "coinbase",
+///     })
+/// );
+///
+/// // Only the asset is required
+/// let order = DigitalOrder {
+///     digital_asset: "ETH".to_string(),
+///     pair: None,
+///     asset_type: None,
+///     order_type: None,
+///     volume: None,
 ///     extra: None,
 /// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&order).unwrap(),
+///     json!({ "$digital_asset": "ETH" })
+/// );
 /// ```
 ///
 /// API Docs: <https://sift.com/developers/docs/curl/events-api/complex-field-types/digital-order>
 ///
 /// [Event]: crate::events::Event
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct DigitalOrder {
     /// The trading name of the asset.
     #[serde(rename = "$digital_asset")]
@@ -806,7 +1074,7 @@ pub struct DigitalOrder {
     #[serde(rename = "$asset_type")]
     pub asset_type: Option<DigitalOrderAssetType>,
 
-    /// Any digital asset that has value or established ownership.
+    /// The type of trade or exchange being made.
     #[serde(rename = "$order_type")]
     pub order_type: Option<DigitalOrderType>,
 
@@ -817,7 +1085,7 @@ pub struct DigitalOrder {
     pub volume: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the digital order.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -829,41 +1097,113 @@ pub struct DigitalOrder {
 /// given discount. Populate only those that apply.
 ///
 /// A discount is an object that gets included as part of promotion object. Learn more about [Promotion]s.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// ```
+/// use serde_json::json;
+/// use sift_science::events::{Discount, Micros};
+///
+/// // 10% off
+/// assert_eq!(
+///     serde_json::to_value(Discount::percent(0.1)).unwrap(),
+///     json!({ "$percentage_off": 0.1 })
+/// );
+///
+/// // $25 off an order of $100 or more
+/// let discount = Discount::amount_off(Micros::from_base_units(2_500), "USD")
+///     .with_minimum_purchase_amount(Micros::from_base_units(10_000));
+/// let json = json!({
+///     "$amount": 25_000_000,
+///     "$currency_code": "USD",
+///     "$minimum_purchase_amount": 100_000_000,
+/// });
+/// assert_eq!(serde_json::to_value(&discount).unwrap(), json);
+/// assert_eq!(serde_json::from_value::<Discount>(json).unwrap(), discount);
+/// ```
+#[skip_serializing_none]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Discount {
     /// The percentage discount. If the discount is 10% off, you would send `0.1`.
     #[serde(rename = "$percentage_off")]
-    pub percentage_off: f32,
+    pub percentage_off: Option<f64>,
 
     /// The amount of the discount that the promotion offers in micros in the base unit of the
     /// `currency_code`. 1 cent = 10,000 micros. $1.23 USD = 123 cents = 1,230,000 micros. For
     /// currencies without cents of fractional denominations, like the Japanese Yen, use 1 JPY =
     /// 1000000 micros.
     #[serde(rename = "$amount")]
-    pub amount: i64,
+    pub amount: Option<Micros>,
 
     /// [ISO-4217] currency code for the amount. e.g., USD, CAD, HKD. If your site uses alternative
     /// currencies, like bitcoin or points systems, specify that here.
     ///
     /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
     #[serde(rename = "$currency_code")]
-    pub currency_code: String,
+    pub currency_code: Option<CurrencyCode>,
 
     /// The minimum amount someone must spend in order for the promotion to be applied. The amount
     /// should be in micros in the base unit of the `currency_code`. 1 cent = 10,000 micros. $1.23
     /// USD = 123 cents = 1,230,000 micros. For currencies without cents of fractional
     /// denominations, like the Japanese Yen, use 1 JPY = 1000000 micros.
     #[serde(rename = "$minimum_purchase_amount")]
-    pub minimum_purchase_amount: i64,
+    pub minimum_purchase_amount: Option<Micros>,
 
     /// Any extra non-reserved fields to be recorded with the discount.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
+impl Discount {
+    /// A percentage discount, e.g. `0.1` for 10% off.
+    pub fn percent(percentage_off: f64) -> Self {
+        Discount {
+            percentage_off: Some(percentage_off),
+            ..Default::default()
+        }
+    }
+
+    /// A fixed amount off, in micros of the currency's base unit.
+    ///
+    /// Like `From<&str>` for [CurrencyCode], the currency is not checked.
+    pub fn amount_off(amount: Micros, currency: impl Into<CurrencyCode>) -> Self {
+        Discount {
+            amount: Some(amount),
+            currency_code: Some(currency.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A fixed amount off, in the currency's major unit.
+    ///
+    /// Returns an [Error::Validation] for an amount or currency that [Money::from_major_units]
+    /// rejects.
+    ///
+    /// ```
+    /// use sift_science::{
+    ///     events::{Discount, Micros},
+    ///     Decimal,
+    /// };
+    ///
+    /// let discount = Discount::from_major_units(Decimal::new(25, 0), "usd").unwrap();
+    /// assert_eq!(discount, Discount::amount_off(Micros::from_base_units(2_500), "USD"));
+    ///
+    /// assert!(Discount::from_major_units(Decimal::new(2499, 2), "JPY").is_err());
+    /// ```
+    pub fn from_major_units(amount: Decimal, currency: impl AsRef<str>) -> Result<Self> {
+        let Money { amount, currency } = Money::from_major_units(amount, currency)?;
+        Ok(Discount::amount_off(amount, currency))
+    }
+
+    /// Set the minimum amount someone must spend for the discount to apply, in the same currency
+    /// as the discount.
+    pub fn with_minimum_purchase_amount(mut self, amount: Micros) -> Self {
+        self.minimum_purchase_amount = Some(amount);
+        self
+    }
+}
+
 /// The Guest field type represents a person using a booking.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Guest {
     /// Name of the individual on the booking.
     #[serde(rename = "$name")]
@@ -896,17 +1236,43 @@ pub struct Guest {
     pub birth_date: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the guest.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
+#[cfg(feature = "phonenumber")]
+impl Guest {
+    /// Set the guest's phone number, normalized to E.164 using `default_region` for national
+    /// numbers.
+    ///
+    /// Numbers that can't be normalized are set as is. See [normalize_phone].
+    ///
+    /// Only available with the `phonenumber` feature.
+    ///
+    /// ```
+    /// use sift_science::events::Guest;
+    ///
+    /// let guest = Guest::default().with_phone_e164("(415) 555-6041", "US");
+    /// assert_eq!(guest.phone.as_deref(), Some("+14155556041"));
+    /// ```
+    ///
+    /// [normalize_phone]: crate::events::normalize_phone
+    pub fn with_phone_e164(mut self, phone: impl Into<String>, default_region: &str) -> Self {
+        self.phone = Some(crate::events::phone::normalize_phone_or_raw(
+            phone.into(),
+            default_region,
+        ));
+        self
+    }
+}
+
 /// The Image complex type represents an image hosted on your website or app,
 /// typically uploaded by a user.
 ///
 /// Used in the in one of the `Event::CreateContent` or `Event::UpdateContent`
 /// events.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Image {
     /// The MD5 hash of the image file.
     ///
@@ -924,7 +1290,7 @@ pub struct Image {
     pub description: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the image.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -936,7 +1302,7 @@ pub struct Image {
 /// (such as OTAs, Rideshare, Vehicle rentals, Hotels, etc) should use `Booking` instead to
 /// leverage Sift's specialization in Travel and Ticketing use cases.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Item {
     /// The item's unique identifier according to your systems.
     ///
@@ -960,7 +1326,7 @@ pub struct Item {
     ///
     /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
     #[serde(rename = "$currency_code")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 
     /// Quantity of the item.
     #[serde(rename = "$quantity")]
@@ -1013,13 +1379,33 @@ pub struct Item {
     pub size: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the item.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
+impl Item {
+    /// Set the item's unit price and its currency.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::events::{Item, Micros, Money};
+    ///
+    /// let item = Item::default().with_price(Money::new(Micros::from_base_units(1_999), "USD"));
+    /// assert_eq!(
+    ///     serde_json::to_value(&item).unwrap(),
+    ///     json!({ "$price": 19_990_000, "$currency_code": "USD" })
+    /// );
+    /// ```
+    pub fn with_price(mut self, price: Money) -> Self {
+        self.price = Some(price.amount);
+        self.currency_code = Some(price.currency);
+        self
+    }
+}
+
 /// Contains information about the merchant or seller providing goods or service.
 #[skip_serializing_none]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct MerchantProfile {
     /// The internal identifier for the merchant or seller providing the good or service.
     #[serde(rename = "$merchant_id")]
@@ -1042,7 +1428,7 @@ pub struct MerchantProfile {
     pub merchant_address: Option<Address>,
 
     /// Any extra non-reserved fields to be recorded with the merchant profile.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1058,7 +1444,7 @@ pub struct MerchantProfile {
 /// in `Transaction` events, but not in both. Choose the time where you have the most information to
 /// send or where it is easiest to include.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct OrderedFrom {
     /// The customer's internal identifier for the specific physical location providing the good or
     /// service.
@@ -1070,7 +1456,7 @@ pub struct OrderedFrom {
     pub store_address: Option<Address>,
 
     /// Any extra non-reserved fields to be recorded with the location.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1079,7 +1465,7 @@ pub struct OrderedFrom {
 /// The value must be a nested object with the appropriate item subfields for the given payment
 /// method. Generally used with `Event::CreateOrder` or `Event::Transaction`.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct PaymentMethod {
     /// The general type of payment being used.
     #[serde(rename = "$payment_type")]
@@ -1144,6 +1530,9 @@ pub struct PaymentMethod {
 
     /// In case of a declined payment, response code received from the payment processor indicating
     /// the reason for the decline.
+    ///
+    /// Prefer [PaymentMethod::with_decline_reason] so the processor is recorded along with the
+    /// code.
     #[serde(rename = "$decline_reason_code")]
     pub decline_reason_code: Option<String>,
 
@@ -1155,6 +1544,12 @@ pub struct PaymentMethod {
     #[serde(rename = "$wallet_type")]
     pub wallet_type: Option<WalletType>,
 
+    /// The digital wallet used for the payment, e.g. Apple Pay or Google Pay.
+    ///
+    /// Use with [PaymentType::DigitalWallet].
+    #[serde(rename = "$digital_wallet")]
+    pub digital_wallet: Option<DigitalWallet>,
+
     /// Payer ID returned by Paypal.
     #[serde(rename = "$paypal_payer_id")]
     pub paypal_payer_id: Option<String>,
@@ -1222,10 +1617,32 @@ pub struct PaymentMethod {
     pub bank_country: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the payment method.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
+impl PaymentMethod {
+    /// Record a processor decline reason on this payment method.
+    ///
+    /// Sets both the `payment_gateway` and `decline_reason_code` so Sift can interpret the code
+    /// for the processor that issued it.
+    ///
+    /// ```
+    /// use sift_science::events::{DeclineReason, PaymentMethod, PaymentProcessor};
+    ///
+    /// let payment_method = PaymentMethod::default()
+    ///     .with_decline_reason(DeclineReason::new(PaymentProcessor::Stripe, "card_declined"));
+    ///
+    /// assert_eq!(payment_method.payment_gateway.as_deref(), Some("$stripe"));
+    /// assert_eq!(payment_method.decline_reason_code.as_deref(), Some("card_declined"));
+    /// ```
+    pub fn with_decline_reason(mut self, reason: DeclineReason) -> Self {
+        self.payment_gateway = Some(reason.processor.into());
+        self.decline_reason_code = Some(reason.code);
+        self
+    }
+}
+
 /// Promotions such as referrals, coupons, free trials, etc.
 ///
 /// Populate with the appropriate information to describe the promotion. Not all sub-fields will
@@ -1236,7 +1653,7 @@ pub struct PaymentMethod {
 /// $25 coupon on first order) and non-monetary (e.g. "1000 in game points to refer a friend")
 /// types.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Promotion {
     /// The ID within your system that you use to represent this promotion. This ID is ideally
     /// unique to the promotion across users (e.g. "BackToSchool2016").
@@ -1278,7 +1695,7 @@ pub struct Promotion {
     pub credit_point: Option<CreditPoint>,
 
     /// Any extra non-reserved fields to be recorded with the promotion.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1295,7 +1712,7 @@ pub struct Promotion {
 /// Even if there's only a single segment associated with the booking, use segment to send valuable
 /// information about the trip.
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Segment {
     /// The address of the start of the journey.
     ///
@@ -1316,6 +1733,7 @@ pub struct Segment {
     /// This may be departure time for a flight, the expected pickup time for a rideshare, etc.
     #[serde(
         rename = "$start_time",
+        default,
         deserialize_with = "deserialize_opt_ms",
         serialize_with = "serialize_opt_ms"
     )]
@@ -1326,6 +1744,7 @@ pub struct Segment {
     /// This may be departure time for a flight, the expected pickup time for a rideshare, etc.
     #[serde(
         rename = "$end_time",
+        default,
         deserialize_with = "deserialize_opt_ms",
         serialize_with = "serialize_opt_ms"
     )]
@@ -1360,12 +1779,12 @@ pub struct Segment {
     pub fare_class: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the segment.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
 /// Any digital asset that has value or established ownership.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum DigitalOrderAssetType {
     /// Coin assets
     #[serde(rename = "$coin")]
@@ -1397,7 +1816,7 @@ pub enum DigitalOrderAssetType {
 }
 
 /// The type of trade or exchange being made.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum DigitalOrderType {
     /// Limit orders
     #[serde(rename = "$limit")]
@@ -1425,7 +1844,7 @@ pub enum DigitalOrderType {
 }
 
 /// The type of wallet used in the payment.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum WalletType {
     /// Crypto currency wallet
     #[serde(rename = "$crypto")]
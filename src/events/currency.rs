@@ -0,0 +1,211 @@
+//! ISO-4217 currency codes and metadata.
+
+use crate::events::Micros;
+use crate::{Error, Result};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// An [ISO-4217] alpha-3 currency code, e.g. `USD`.
+///
+/// [CurrencyCode::new] checks that the code is three letters and uppercases it, so "usd" becomes
+/// "USD". Codes outside ISO-4217, such as "XBT" for bitcoin, are accepted since Sift allows
+/// alternative currencies. Serializes as a plain string, exactly as a `String` code would.
+///
+/// ```
+/// use sift_science::events::CurrencyCode;
+///
+/// let usd = CurrencyCode::new("usd").unwrap();
+/// assert_eq!(usd, "USD");
+/// assert_eq!(usd.minor_unit_exponent(), Some(2));
+/// assert_eq!(serde_json::to_value(&usd).unwrap(), serde_json::to_value("USD").unwrap());
+///
+/// assert!(CurrencyCode::new("US").is_err());
+/// assert!(CurrencyCode::new("US$").is_err());
+/// assert!("dollars".parse::<CurrencyCode>().is_err());
+/// ```
+///
+/// The `From<&str>` and `From<String>` conversions are legacy, kept so that `"USD".into()` call
+/// sites written against `String` codes still compile. They convert without any checks; use
+/// [CurrencyCode::new] instead.
+///
+/// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CurrencyCode(String);
+
+impl CurrencyCode {
+    /// Create a currency code, checking that it's three letters and uppercasing it.
+    ///
+    /// Returns an [Error::Validation] for anything else.
+    pub fn new(code: impl AsRef<str>) -> Result<Self> {
+        let code = code.as_ref().trim();
+
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(Error::Validation(format!(
+                "{:?} is not an ISO-4217 alpha-3 currency code",
+                code
+            )));
+        }
+
+        Ok(CurrencyCode(code.to_ascii_uppercase()))
+    }
+
+    /// The code as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Number of minor-unit digits for the currency, e.g. 2 for `USD`, or `None` for codes
+    /// outside ISO-4217.
+    pub fn minor_unit_exponent(&self) -> Option<u32> {
+        minor_unit_exponent(&self.0)
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Self> {
+        CurrencyCode::new(code)
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Legacy unchecked conversion, kept for `"USD".into()` call sites; use [CurrencyCode::new]
+/// instead.
+impl From<&str> for CurrencyCode {
+    fn from(code: &str) -> Self {
+        CurrencyCode(code.into())
+    }
+}
+
+/// Legacy unchecked conversion, kept for `String` call sites; use [CurrencyCode::new] instead.
+impl From<String> for CurrencyCode {
+    fn from(code: String) -> Self {
+        CurrencyCode(code)
+    }
+}
+
+impl From<CurrencyCode> for String {
+    fn from(code: CurrencyCode) -> Self {
+        code.0
+    }
+}
+
+impl PartialEq<str> for CurrencyCode {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for CurrencyCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// An amount of money in a currency.
+///
+/// Serializes to the same `$amount` and `$currency_code` fields Sift uses wherever an amount is
+/// sent, so it can be flattened into a struct in place of the two fields.
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use serde_json::json;
+/// use sift_science::events::{Micros, Money};
+///
+/// let money = Money::new(Micros::from_base_units(123), "USD");
+/// assert_eq!(
+///     serde_json::to_value(&money).unwrap(),
+///     json!({ "$amount": 1_230_000, "$currency_code": "USD" })
+/// );
+/// assert_eq!(serde_json::from_value::<Money>(json!({ "$amount": 1_230_000, "$currency_code": "USD" })).unwrap(), money);
+///
+/// let money = Money::from_major_units(Decimal::new(123, 2), "usd").unwrap();
+/// assert_eq!(money.amount, Micros::from_raw(1_230_000));
+/// assert_eq!(money.currency, "USD");
+///
+/// assert!(Money::from_major_units(Decimal::new(15, 1), "JPY").is_err());
+/// assert!(Money::from_major_units(Decimal::ONE, "dollars").is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Money {
+    /// The amount in micros in the base unit of the `currency`.
+    #[serde(rename = "$amount")]
+    pub amount: Micros,
+
+    /// [ISO-4217] currency code for the amount.
+    ///
+    /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
+    #[serde(rename = "$currency_code")]
+    pub currency: CurrencyCode,
+}
+
+impl Money {
+    /// Create an amount of money in a currency.
+    ///
+    /// Like `From<&str>` for [CurrencyCode], the currency is not checked.
+    pub fn new(amount: Micros, currency: impl Into<CurrencyCode>) -> Self {
+        Money {
+            amount,
+            currency: currency.into(),
+        }
+    }
+
+    /// Create an amount of money from an amount in the currency's major unit.
+    ///
+    /// Returns an [Error::Validation] for an invalid currency code, or an amount that
+    /// [Micros::from_major_units] rejects.
+    pub fn from_major_units(amount: Decimal, currency: impl AsRef<str>) -> Result<Self> {
+        let currency = CurrencyCode::new(currency)?;
+        let amount = Micros::from_major_units(amount, &currency)?;
+        Ok(Money { amount, currency })
+    }
+}
+
+/// Number of minor-unit digits for an ISO-4217 currency code, e.g. 2 for `USD`, 0 for `JPY` and
+/// 3 for `BHD`.
+///
+/// Returns `None` for unknown codes.
+pub(crate) fn minor_unit_exponent(currency: &str) -> Option<u32> {
+    match currency.to_ascii_uppercase().as_str() {
+        // Zero-decimal currencies
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => Some(0),
+
+        // Three-decimal currencies
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => Some(3),
+
+        // Four-decimal currencies
+        "CLF" | "UYW" => Some(4),
+
+        "AED" | "AFN" | "ALL" | "AMD" | "ANG" | "AOA" | "ARS" | "AUD" | "AWG" | "AZN" | "BAM"
+        | "BBD" | "BDT" | "BGN" | "BMD" | "BND" | "BOB" | "BOV" | "BRL" | "BSD" | "BTN" | "BWP"
+        | "BYN" | "BZD" | "CAD" | "CDF" | "CHE" | "CHF" | "CHW" | "CNY" | "COP" | "COU" | "CRC"
+        | "CUC" | "CUP" | "CVE" | "CZK" | "DKK" | "DOP" | "DZD" | "EGP" | "ERN" | "ETB" | "EUR"
+        | "FJD" | "FKP" | "GBP" | "GEL" | "GHS" | "GIP" | "GMD" | "GTQ" | "GYD" | "HKD" | "HNL"
+        | "HTG" | "HUF" | "IDR" | "ILS" | "INR" | "IRR" | "JMD" | "KES" | "KGS" | "KHR" | "KPW"
+        | "KYD" | "KZT" | "LAK" | "LBP" | "LKR" | "LRD" | "LSL" | "MAD" | "MDL" | "MGA" | "MKD"
+        | "MMK" | "MNT" | "MOP" | "MRU" | "MUR" | "MVR" | "MWK" | "MXN" | "MXV" | "MYR" | "MZN"
+        | "NAD" | "NGN" | "NIO" | "NOK" | "NPR" | "NZD" | "PAB" | "PEN" | "PGK" | "PHP" | "PKR"
+        | "PLN" | "QAR" | "RON" | "RSD" | "RUB" | "SAR" | "SBD" | "SCR" | "SDG" | "SEK" | "SGD"
+        | "SHP" | "SLE" | "SLL" | "SOS" | "SRD" | "SSP" | "STN" | "SVC" | "SYP" | "SZL" | "THB"
+        | "TJS" | "TMT" | "TOP" | "TRY" | "TTD" | "TWD" | "TZS" | "UAH" | "USD" | "USN" | "UYU"
+        | "UZS" | "VED" | "VES" | "WST" | "XCD" | "YER" | "ZAR" | "ZMW" | "ZWL" => Some(2),
+
+        _ => None,
+    }
+}
@@ -52,23 +52,65 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::borrow::Cow;
 use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
 use std::time::Duration;
 use std::time::SystemTime;
 
+mod builders;
 mod complex_field_types;
+mod currency;
+#[cfg(feature = "phonenumber")]
+mod phone;
 mod reserved_events;
 mod reserved_fields;
 
+pub use builders::*;
 pub use complex_field_types::*;
+pub use currency::{CurrencyCode, Money};
+#[cfg(feature = "phonenumber")]
+pub use phone::normalize_phone;
 pub use reserved_events::*;
 pub use reserved_fields::*;
 
 use crate::common::{abuse_type_serialize, deserialize_ms, serialize_ms, AbuseType};
+use crate::{Error, Result};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+
+const MICROS_PER_UNIT: u64 = 1_000_000;
 
 /// Base unit for currencies.
 ///
 /// 1 cent = 10,000 micros. $1.23 USD = 123 cents = 1,230,000 micros.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// ```
+/// use sift_science::events::Micros;
+///
+/// let shirt = Micros::from_base_units(1_999);
+/// let socks = Micros::from_base_units(499);
+///
+/// assert_eq!((shirt + socks).as_raw(), 24_980_000);
+/// assert_eq!([shirt, socks].into_iter().sum::<Micros>(), shirt + socks);
+/// assert!(socks < shirt);
+/// assert_eq!(u64::from(socks), 4_990_000);
+/// ```
+///
+/// Adding and summing saturate at [u64::MAX] rather than overflowing. Use [Micros::checked_add]
+/// or [Micros::checked_sum] to detect overflow instead:
+///
+/// ```
+/// use sift_science::events::Micros;
+///
+/// let max = Micros::from_raw(u64::MAX);
+/// let one = Micros::from_raw(1);
+///
+/// assert_eq!(max + one, max);
+/// assert_eq!([max, one].into_iter().sum::<Micros>(), max);
+/// assert_eq!(max.checked_add(one), None);
+/// assert_eq!(Micros::checked_sum([max, one]), None);
+/// assert_eq!(Micros::checked_sum([one, one]), Some(Micros::from_raw(2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Micros(u64);
 
 impl Micros {
@@ -89,10 +131,125 @@ impl Micros {
     pub fn from_raw(raw: u64) -> Self {
         Micros(raw)
     }
+
+    /// The raw value in micros.
+    pub fn as_raw(&self) -> u64 {
+        self.0
+    }
+
+    /// Add two amounts, returning `None` on overflow.
+    pub fn checked_add(self, rhs: Micros) -> Option<Micros> {
+        self.0.checked_add(rhs.0).map(Micros)
+    }
+
+    /// Sum amounts, returning `None` on overflow.
+    pub fn checked_sum(amounts: impl IntoIterator<Item = Micros>) -> Option<Micros> {
+        amounts.into_iter().try_fold(Micros(0), Micros::checked_add)
+    }
+
+    /// Create a new `Micros` instance from an amount in a currency's major unit.
+    ///
+    /// 1 unit of any currency is 1,000,000 micros, regardless of its minor unit. The amount may
+    /// not be more precise than the currency's [ISO-4217] minor unit, e.g. `1.5` is rejected for
+    /// JPY and `1.2345` is rejected for BHD.
+    ///
+    /// Returns an [Error::Validation] for unknown currency codes, negative amounts, or amounts
+    /// with too many decimal places.
+    ///
+    /// ```
+    /// use sift_science::{
+    ///     events::{CurrencyCode, Micros},
+    ///     Decimal,
+    /// };
+    ///
+    /// // $1.23 USD
+    /// let usd = Micros::from_major_units(Decimal::new(123, 2), "USD").unwrap();
+    /// assert_eq!(usd.as_major_units("USD"), Decimal::new(123, 2));
+    ///
+    /// // ¥1000 JPY
+    /// let jpy = Micros::from_major_units(Decimal::new(1000, 0), "JPY").unwrap();
+    /// assert_eq!(jpy.as_major_units("JPY"), Decimal::new(1000, 0));
+    /// assert!(Micros::from_major_units(Decimal::new(15, 1), "JPY").is_err());
+    ///
+    /// // 1.234 BHD
+    /// let bhd = Micros::from_major_units(Decimal::new(1234, 3), "BHD").unwrap();
+    /// assert_eq!(bhd.as_major_units("BHD"), Decimal::new(1234, 3));
+    /// assert!(Micros::from_major_units(Decimal::new(12345, 4), "BHD").is_err());
+    ///
+    /// assert!(Micros::from_major_units(Decimal::ONE, "XYZ").is_err());
+    ///
+    /// // Currency codes work too
+    /// let eur = CurrencyCode::new("eur").unwrap();
+    /// let micros = Micros::from_major_units(Decimal::new(995, 2), &eur).unwrap();
+    /// assert_eq!(micros.as_major_units(&eur), Decimal::new(995, 2));
+    /// ```
+    ///
+    /// [ISO-4217]: https://en.wikipedia.org/wiki/ISO_4217
+    pub fn from_major_units(amount: Decimal, currency: impl AsRef<str>) -> Result<Self> {
+        let currency = currency.as_ref();
+        let exponent = currency::minor_unit_exponent(currency)
+            .ok_or_else(|| Error::Validation(format!("unknown currency code {}", currency)))?;
+
+        if amount.is_sign_negative() && !amount.is_zero() {
+            return Err(Error::Validation(format!(
+                "negative amount {} {}",
+                amount, currency
+            )));
+        }
+
+        if amount.normalize().scale() > exponent {
+            return Err(Error::Validation(format!(
+                "amount {} has more than {} decimal places for {}",
+                amount, exponent, currency
+            )));
+        }
+
+        amount
+            .checked_mul(Decimal::from(MICROS_PER_UNIT))
+            .and_then(|micros| micros.to_u64())
+            .map(Micros)
+            .ok_or_else(|| {
+                Error::Validation(format!("amount {} {} is too large", amount, currency))
+            })
+    }
+
+    /// The amount in a currency's major unit, rounded to the currency's [ISO-4217] minor unit.
+    ///
+    /// Unknown currency codes are not rounded.
+    ///
+    /// [ISO-4217]: https://en.wikipedia.org/wiki/ISO_4217
+    pub fn as_major_units(&self, currency: impl AsRef<str>) -> Decimal {
+        let currency = currency.as_ref();
+        let amount = Decimal::from(self.0) / Decimal::from(MICROS_PER_UNIT);
+        match currency::minor_unit_exponent(currency) {
+            Some(exponent) => amount.round_dp(exponent),
+            None => amount.normalize(),
+        }
+    }
+}
+
+impl From<Micros> for u64 {
+    fn from(micros: Micros) -> Self {
+        micros.0
+    }
+}
+
+impl Add for Micros {
+    type Output = Micros;
+
+    fn add(self, rhs: Micros) -> Self::Output {
+        Micros(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sum for Micros {
+    fn sum<I: Iterator<Item = Micros>>(iter: I) -> Self {
+        iter.fold(Micros(0), Add::add)
+    }
 }
 
 /// Optional parameters for event requests.
-#[derive(Debug, Default)]
+#[derive(Clone, Default)]
 pub struct EventOptions {
     /// If true, requests that the response include a score for this user, computed using the
     /// submitted event.
@@ -127,6 +284,63 @@ pub struct EventOptions {
 
     /// Overrides the URI path for this API call.
     pub path: Option<Cow<'static, str>>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
+
+    /// If true, retries this call according to the client's [RetryPolicy].
+    ///
+    /// Off by default: a request that timed out may still have reached Sift, and sending it again
+    /// would track the event twice.
+    ///
+    /// [RetryPolicy]: crate::RetryPolicy
+    pub retry: Option<bool>,
+}
+
+impl EventOptions {
+    /// Options requesting synchronous scores for the given abuse types.
+    pub fn scored(abuse_types: Vec<AbuseType>) -> Self {
+        EventOptions {
+            return_score: Some(true),
+            abuse_types: Some(abuse_types),
+            ..Default::default()
+        }
+    }
+
+    /// Overrides the timeout for this call.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retries this call according to the client's [RetryPolicy], for events where a duplicate
+    /// is acceptable.
+    ///
+    /// [RetryPolicy]: crate::RetryPolicy
+    #[must_use]
+    pub fn with_retry(mut self) -> Self {
+        self.retry = Some(true);
+        self
+    }
+}
+
+// The api key is masked, since options are logged when tracking events
+impl fmt::Debug for EventOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventOptions")
+            .field("return_score", &self.return_score)
+            .field("abuse_types", &self.abuse_types)
+            .field("return_action", &self.return_action)
+            .field("return_workflow_status", &self.return_workflow_status)
+            .field("timeout", &self.timeout)
+            .field("api_key", &self.api_key.as_ref().map(|_| "****"))
+            .field("version", &self.version)
+            .field("path", &self.path)
+            .field("extra_headers", &self.extra_headers)
+            .field("retry", &self.retry)
+            .finish()
+    }
 }
 
 /// Query params accepted by the events API.
@@ -171,23 +385,59 @@ impl From<EventOptions> for EventQueryParams {
 /// Events API response.
 ///
 /// <https://sift.com/developers/docs/curl/score-api/synchronous-scores/overview>
+///
+/// [Client::track](crate::Client::track) checks the outer `status`, whether the event was
+/// accepted, before the inner `status` of the scores, and returns an [Error::Request] for the
+/// first that is non-zero.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct EventResponse {
-    pub(crate) status: i32,
-    pub(crate) error_message: String,
-    pub(crate) score_response: Option<ScoreResponse>,
+    /// The success or error code.
+    pub status: i32,
+
+    /// Description of error if applicable.
+    #[serde(default)]
+    pub error_message: String,
+
+    /// The requested scoring information, if `return_score` or `abuse_types` were set.
+    pub score_response: Option<ScoreResponse>,
 }
 
 /// The requested scoring information for the given user.
 ///
 /// <https://sift.com/developers/docs/curl/score-api/get-score/overview>
+///
+/// Fields that this library doesn't model are ignored, unless the `strict-parsing` feature is
+/// enabled, in which case they fail to deserialize. The same applies to the other response types.
+///
+/// ```
+/// use serde_json::json;
+/// use sift_science::events::ScoreResponse;
+///
+/// let response = json!({
+///     "status": 0,
+///     "error_message": "OK",
+///     "entity_id": "billy_jones_301",
+///     "scores": { "payment_abuse": { "score": 0.9, "reasons": [] } },
+///     "new_field": true,
+/// });
+///
+/// let parsed = serde_json::from_value::<ScoreResponse>(response);
+/// if cfg!(feature = "strict-parsing") {
+///     assert!(parsed.unwrap_err().to_string().contains("unknown field `new_field`"));
+/// } else {
+///     assert_eq!(parsed.unwrap().entity_id.as_deref(), Some("billy_jones_301"));
+/// }
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct ScoreResponse {
     /// The success or error code.
     pub status: i32,
 
     /// Description of error if applicable.
+    #[serde(default)]
     pub error_message: String,
 
     /// Contains the computed scores for all applicable abuse types.
@@ -215,13 +465,234 @@ pub struct ScoreResponse {
     /// request; we always include all decisions that have been applied to the given entity.
     ///
     /// The map is keyed by abuse type, which could be one of: `payment_abuse`, `account_abuse`,
-    /// `content_abuse`, `promotion_abuse`, `account_takeover`.
+    /// `content_abuse`, `promotion_abuse`, `account_takeover`. See
+    /// [ScoreResponse::latest_decisions_typed] for the parsed decisions.
     pub latest_decisions: Option<serde_json::Value>,
+
+    /// Actions triggered by the tracked event, if `return_action` was requested.
+    pub actions: Option<Vec<Action>>,
+
+    /// The status of workflows run as a result of the tracked event, or for the scored user, if
+    /// `return_workflow_status` was requested.
+    pub workflow_statuses: Option<Vec<WorkflowStatus>>,
+}
+
+impl ScoreResponse {
+    /// The latest decisions applied to the entity, by abuse type.
+    ///
+    /// [ScoreResponse::latest_decisions] keeps the raw JSON, e.g. for abuse types this client does
+    /// not know about yet.
+    ///
+    /// ```
+    /// # #[cfg(feature = "decisions")]
+    /// # {
+    /// use serde_json::json;
+    /// use sift_science::events::ScoreResponse;
+    ///
+    /// let score_response: ScoreResponse = serde_json::from_value(json!({
+    ///     "status": 0,
+    ///     "error_message": "OK",
+    ///     "latest_decisions": {
+    ///         "payment_abuse": {
+    ///             "decision": { "id": "block_user_payment_abuse" },
+    ///             "time": 1461963439151u64,
+    ///             "webhook_succeeded": true
+    ///         },
+    ///         "account_takeover": {
+    ///             "decision": { "id": "lock_account_account_takeover" },
+    ///             "time": 1461963439151u64,
+    ///             "webhook_succeeded": false
+    ///         }
+    ///     }
+    /// }))
+    /// .unwrap();
+    ///
+    /// let decisions = score_response.latest_decisions_typed().unwrap().unwrap();
+    ///
+    /// let payment_abuse = decisions.payment_abuse.unwrap();
+    /// assert_eq!(payment_abuse.decision.id, "block_user_payment_abuse");
+    /// assert_eq!(payment_abuse.webhook_succeeded, Some(true));
+    ///
+    /// let account_takeover = decisions.account_takeover.unwrap();
+    /// assert_eq!(account_takeover.decision.id, "lock_account_account_takeover");
+    /// assert_eq!(account_takeover.webhook_succeeded, Some(false));
+    ///
+    /// assert!(decisions.content_abuse.is_none());
+    /// # }
+    /// ```
+    #[cfg(feature = "decisions")]
+    pub fn latest_decisions_typed(&self) -> Result<Option<crate::decisions::Decisions>> {
+        let latest_decisions = match &self.latest_decisions {
+            Some(latest_decisions) => latest_decisions,
+            None => return Ok(None),
+        };
+
+        Ok(Some(serde_json::from_value(latest_decisions.clone())?))
+    }
+}
+
+/// Everything Sift returned about a tracked event.
+///
+/// See [Client::track_full](crate::Client::track_full).
+#[derive(Debug, Default)]
+pub struct TrackOutcome {
+    /// The computed scores, if `return_score` or `abuse_types` were requested.
+    pub scores: Option<Scores>,
+
+    /// Actions triggered by the tracked event, if `return_action` was requested.
+    pub actions: Vec<Action>,
+
+    /// The status of workflows run as a result of the tracked event, if `return_workflow_status`
+    /// was requested.
+    pub workflow_statuses: Vec<WorkflowStatus>,
+}
+
+impl From<ScoreResponse> for TrackOutcome {
+    fn from(score_response: ScoreResponse) -> Self {
+        TrackOutcome {
+            scores: score_response.scores,
+            actions: score_response.actions.unwrap_or_default(),
+            workflow_statuses: score_response.workflow_statuses.unwrap_or_default(),
+        }
+    }
+}
+
+/// An action triggered by a tracked event.
+///
+/// Returned by [Client::track_full] when `return_action` is requested.
+///
+/// [Client::track_full]: crate::Client::track_full
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct Action {
+    /// The id of this action instance.
+    pub id: String,
+
+    /// The action that was triggered.
+    pub action: ActionIdentifier,
+
+    /// The entity the action was triggered for.
+    pub entity: ActionEntity,
+
+    /// The time the action was triggered.
+    #[serde(serialize_with = "serialize_ms", deserialize_with = "deserialize_ms")]
+    pub time: SystemTime,
+
+    /// What triggered the action.
+    #[serde(default)]
+    pub triggers: Vec<ActionTrigger>,
+}
+
+/// The action reference
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct ActionIdentifier {
+    /// The action's id
+    pub id: String,
+}
+
+/// The entity an action was triggered for
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct ActionEntity {
+    /// The entity's id
+    pub id: String,
+}
+
+/// What triggered an action.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct ActionTrigger {
+    /// The type of trigger, e.g. `formula`.
+    #[serde(rename = "type")]
+    pub trigger_type: String,
+
+    /// The source of the trigger, e.g. `synchronous_action`.
+    pub source: Option<String>,
+
+    /// The id of the trigger.
+    pub trigger_id: Option<String>,
+}
+
+/// The status of a workflow run as a result of a tracked event.
+///
+/// <https://sift.com/developers/docs/curl/workflows-api/workflow-decisions>
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct WorkflowStatus {
+    /// The id of the workflow run.
+    pub id: String,
+
+    /// The workflow configuration that was run.
+    pub config: Option<WorkflowConfig>,
+
+    /// The display name of the workflow configuration.
+    pub config_display_name: Option<String>,
+
+    /// The abuse types the workflow applies to.
+    #[serde(default)]
+    pub abuse_types: Vec<AbuseType>,
+
+    /// The state of the workflow run, e.g. `running`, `finished` or `failed`.
+    pub state: String,
+
+    /// The entity the workflow was run for.
+    pub entity: WorkflowEntity,
+
+    /// The apps run as part of the workflow, e.g. decisions and review queues.
+    #[serde(default)]
+    pub history: Vec<WorkflowHistory>,
+}
+
+/// A workflow configuration reference
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct WorkflowConfig {
+    /// The id of the workflow configuration.
+    pub id: Option<String>,
+
+    /// The version of the workflow configuration.
+    pub version: String,
+}
+
+/// The entity a workflow was run for
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct WorkflowEntity {
+    /// The entity's id
+    pub id: String,
+
+    /// The entity's type, e.g. `user` or `order`.
+    #[serde(rename = "type")]
+    pub entity_type: String,
+}
+
+/// An app run as part of a workflow.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct WorkflowHistory {
+    /// The app, e.g. `decision`, `review_queue`, `user_scorer` or `event_processor`.
+    pub app: String,
+
+    /// The name of the step.
+    pub name: Option<String>,
+
+    /// The state of the step.
+    pub state: String,
+
+    /// The step configuration, e.g. the `decision_id` applied by a decision app.
+    pub config: Option<serde_json::Value>,
 }
 
 /// Contains all computed scores for all applicable abuse types for a given user.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct Scores {
     /// Score associated with the payment abuse type
     pub payment_abuse: Option<AbuseScore>,
@@ -237,11 +708,58 @@ pub struct Scores {
 
     /// Score associated with the content abuse type
     pub content_abuse: Option<AbuseScore>,
+
+    /// The legacy Sift Score, for custom fraud types
+    pub legacy: Option<AbuseScore>,
+}
+
+impl Scores {
+    /// The score for an abuse type, if Sift returned one.
+    ///
+    /// ```
+    /// use sift_science::{events::Scores, AbuseType};
+    ///
+    /// let scores: Scores = serde_json::from_value(serde_json::json!({
+    ///     "payment_abuse": { "score": 0.9 },
+    ///     "account_takeover": { "score": 0.1 }
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(scores.get(AbuseType::PaymentAbuse).unwrap().score, 0.9);
+    /// assert!(scores.get(AbuseType::PromoAbuse).is_none());
+    ///
+    /// let present = scores
+    ///     .iter()
+    ///     .map(|(abuse_type, score)| (abuse_type, score.score))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     present,
+    ///     vec![(AbuseType::PaymentAbuse, 0.9), (AbuseType::AccountTakeover, 0.1)]
+    /// );
+    /// ```
+    pub fn get(&self, abuse_type: AbuseType) -> Option<&AbuseScore> {
+        match abuse_type {
+            AbuseType::PaymentAbuse => self.payment_abuse.as_ref(),
+            AbuseType::PromoAbuse => self.promotion_abuse.as_ref(),
+            AbuseType::AccountAbuse => self.account_abuse.as_ref(),
+            AbuseType::AccountTakeover => self.account_takeover.as_ref(),
+            AbuseType::ContentAbuse => self.content_abuse.as_ref(),
+            AbuseType::Legacy => self.legacy.as_ref(),
+        }
+    }
+
+    /// The scores Sift returned, with their abuse types.
+    pub fn iter(&self) -> impl Iterator<Item = (AbuseType, &AbuseScore)> {
+        AbuseType::ALL
+            .into_iter()
+            .filter_map(move |abuse_type| Some((abuse_type, self.get(abuse_type)?)))
+    }
 }
 
 /// Computed score for an abuse type for a given user.
 #[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AbuseScore {
     /// Score for the user between 0.0 and 1.0. A score of 0.5 translates to a score a 50 in the
     /// console.
@@ -259,6 +777,7 @@ pub struct AbuseScore {
 /// The included values will vary based on the user. Includes related users in the details object
 /// when applicable.
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AbuseScoreReason {
     /// Name of the risk signal.
     pub name: String,
@@ -274,6 +793,7 @@ pub struct AbuseScoreReason {
 /// Contains all computed labels for all applicable abuse types for a given entity.
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct LatestLabels {
     /// Label associated with the payment abuse type
     pub payment_abuse: Option<Label>,
@@ -291,9 +811,105 @@ pub struct LatestLabels {
     pub content_abuse: Option<Label>,
 }
 
+impl LatestLabels {
+    /// The label for an abuse type, if the entity has been labeled for it.
+    ///
+    /// ```
+    /// use sift_science::{events::LatestLabels, AbuseType};
+    ///
+    /// let labels: LatestLabels = serde_json::from_value(serde_json::json!({
+    ///     "payment_abuse": { "is_bad": true, "time": 1350201660000u64 },
+    ///     "account_abuse": {
+    ///         "is_bad": false,
+    ///         "time": 1350201660000u64,
+    ///         "description": "known good customer"
+    ///     }
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert!(labels.get(AbuseType::PaymentAbuse).unwrap().is_bad());
+    /// assert!(labels.get(AbuseType::PromoAbuse).is_none());
+    ///
+    /// let present = labels
+    ///     .iter()
+    ///     .map(|(abuse_type, label)| (abuse_type, label.is_bad(), label.description()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     present,
+    ///     vec![
+    ///         (AbuseType::PaymentAbuse, true, None),
+    ///         (AbuseType::AccountAbuse, false, Some("known good customer")),
+    ///     ]
+    /// );
+    ///
+    /// for (_, label) in &labels {
+    ///     assert_eq!(
+    ///         label.applied_at(),
+    ///         std::time::UNIX_EPOCH + std::time::Duration::from_millis(1350201660000)
+    ///     );
+    /// }
+    /// ```
+    pub fn get(&self, abuse_type: AbuseType) -> Option<&Label> {
+        match abuse_type {
+            AbuseType::PaymentAbuse => self.payment_abuse.as_ref(),
+            AbuseType::PromoAbuse => self.promotion_abuse.as_ref(),
+            AbuseType::AccountAbuse => self.account_abuse.as_ref(),
+            AbuseType::AccountTakeover => self.account_takeover.as_ref(),
+            AbuseType::ContentAbuse => self.content_abuse.as_ref(),
+            AbuseType::Legacy => None,
+        }
+    }
+
+    /// The labels applied to the entity, with their abuse types.
+    pub fn iter(&self) -> impl Iterator<Item = (AbuseType, &Label)> {
+        self.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a LatestLabels {
+    type Item = (AbuseType, &'a Label);
+    type IntoIter = std::iter::Flatten<std::array::IntoIter<Option<(AbuseType, &'a Label)>, 6>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        AbuseType::ALL
+            .map(|abuse_type| Some((abuse_type, self.get(abuse_type)?)))
+            .into_iter()
+            .flatten()
+    }
+}
+
 /// Entry for an abuse types for which a given event has been labeled.
+///
+/// ```
+/// use sift_science::{events::ScoreResponse, AbuseType};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let response: ScoreResponse = serde_json::from_value(serde_json::json!({
+///     "status": 0,
+///     "error_message": "OK",
+///     "entity_id": "billy_jones_301",
+///     "latest_labels": {
+///         "payment_abuse": {
+///             "is_bad": true,
+///             "time": 1350201660000u64,
+///             "description": "chargeback"
+///         }
+///     }
+/// }))
+/// .unwrap();
+///
+/// let labels = response.latest_labels.unwrap();
+/// let label = labels.get(AbuseType::PaymentAbuse).unwrap();
+/// assert!(label.is_bad());
+/// assert_eq!(
+///     label.applied_at(),
+///     UNIX_EPOCH + Duration::from_millis(1350201660000)
+/// );
+/// assert_eq!(label.description(), Some("chargeback"));
+/// ```
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct Label {
     /// Indicates whether a user is engaging in behavior deemed harmful to your business.
     ///
@@ -309,10 +925,32 @@ pub struct Label {
     description: Option<String>,
 }
 
+impl Label {
+    /// Whether the user was labeled as engaging in behavior deemed harmful to your business.
+    pub fn is_bad(&self) -> bool {
+        self.is_bad
+    }
+
+    /// The time the label was applied.
+    pub fn applied_at(&self) -> SystemTime {
+        self.time
+    }
+
+    /// Freeform text description of the user and/or incident triggering the label.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
 /// Events API version
-#[derive(Copy, Clone, Debug)]
+///
+/// Defaults to the latest version supported by this library, and can be selected per call with
+/// [EventOptions::version].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ApiVersion {
     /// Version 205
+    #[default]
     V205,
 }
 
@@ -0,0 +1,49 @@
+use phonenumber::{country, Mode};
+
+/// Normalize a phone number to [E.164], e.g. "+14155556041".
+///
+/// Numbers without a country code are parsed as national numbers of `default_region`, an
+/// [ISO-3166] alpha-2 code. Returns `None` when the number can't be parsed or isn't a valid number,
+/// in which case it should be sent to Sift as is.
+///
+/// Only available with the `phonenumber` feature.
+///
+/// ```
+/// use sift_science::events::normalize_phone;
+///
+/// assert_eq!(
+///     normalize_phone("1-415-555-6041", "US").as_deref(),
+///     Some("+14155556041")
+/// );
+/// assert_eq!(
+///     normalize_phone("(415) 555-6041", "us").as_deref(),
+///     Some("+14155556041")
+/// );
+/// assert_eq!(
+///     normalize_phone("+14155556041", "GB").as_deref(),
+///     Some("+14155556041")
+/// );
+/// assert_eq!(normalize_phone("not a phone", "US"), None);
+/// ```
+///
+/// [E.164]: https://en.wikipedia.org/wiki/E.164
+/// [ISO-3166]: http://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+pub fn normalize_phone(raw: &str, default_region: &str) -> Option<String> {
+    let region = default_region
+        .trim()
+        .to_ascii_uppercase()
+        .parse::<country::Id>()
+        .ok();
+    let number = phonenumber::parse(region, raw).ok()?;
+
+    if !phonenumber::is_valid(&number) {
+        return None;
+    }
+
+    Some(number.format().mode(Mode::E164).to_string())
+}
+
+// Normalize a phone number when possible, leaving it untouched otherwise
+pub(crate) fn normalize_phone_or_raw(raw: String, default_region: &str) -> String {
+    normalize_phone(&raw, default_region).unwrap_or(raw)
+}
@@ -1,12 +1,13 @@
-use crate::common::{deserialize_opt_ms, serialize_opt_ms};
+use crate::common::{deserialize_extra, deserialize_opt_ms, is_none_or_empty, serialize_opt_ms};
 use crate::events::{
     complex_field_types::{
         Address, App, Booking, Browser, DigitalOrder, Image, Item, MerchantProfile, OrderedFrom,
         PaymentMethod, Promotion,
     },
     reserved_fields::*,
-    AbuseType, Micros,
+    AbuseType, CurrencyCode, Micros,
 };
+use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::time::SystemTime;
@@ -26,7 +27,208 @@ use std::time::SystemTime;
 /// Each event has fields that provide details and accepts required, reserved, and custom fields.
 ///
 /// <https://sift.com/developers/docs/curl/events-api/overview>
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// # Deserializing
+///
+/// Events deserialize from the same JSON they serialize to, e.g. when read back from a queue
+/// before being forwarded to Sift. Custom fields end up in the `extra` field of the properties,
+/// which is `None` when there are none.
+///
+/// ```
+/// use serde_json::json;
+/// use sift_science::{events::*, AbuseType};
+///
+/// let event = Event::Login {
+///     user_id: "billy_jones_301".into(),
+///     session_id: Some("gigtleqddo84l8cm15qe4il".into()),
+///     properties: LoginProperties {
+///         user_email: Some("bill@gmail.com".into()),
+///         extra: Some(json!({ "login_method": "password" })),
+///         ..Default::default()
+///     },
+/// };
+///
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+/// # assert_eq!(serde_json::to_value(&event).unwrap()["$type"], event.reserved_type());
+/// #
+/// # let extra = || Some(json!({ "custom_field": "custom value" }));
+/// # let address = || {
+/// #     Some(Address {
+/// #         name: Some("Bill Jones".into()),
+/// #         city: Some("San Francisco".into()),
+/// #         extra: extra(),
+/// #         ..Default::default()
+/// #     })
+/// # };
+/// #
+/// # let events = vec![
+/// #     Event::AddItemToCart {
+/// #         user_id: Some("u".into()),
+/// #         session_id: Some("s".into()),
+/// #         properties: AddItemToCartProperties {
+/// #             item: Some(Item {
+/// #                 item_id: Some("i".into()),
+/// #                 extra: extra(),
+/// #                 ..Default::default()
+/// #             }),
+/// #             ..Default::default()
+/// #         },
+/// #     },
+/// #     Event::AddPromotion {
+/// #         user_id: "u".into(),
+/// #         properties: AddPromotionProperties {
+/// #             promotions: Some(vec![Promotion {
+/// #                 promotion_id: Some("p".into()),
+/// #                 ..Default::default()
+/// #             }]),
+/// #             ..Default::default()
+/// #         },
+/// #     },
+/// #     Event::Chargeback {
+/// #         order_id: Some("o".into()),
+/// #         transaction_id: None,
+/// #         properties: ChargebackProperties {
+/// #             user_id: Some("u".into()),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         },
+/// #     },
+/// #     Event::ContentStatus {
+/// #         user_id: "u".into(),
+/// #         content_id: "c".into(),
+/// #         status: ContentStatus::Active,
+/// #         properties: ContentStatusProperties::default(),
+/// #     },
+/// #     Event::CreateAccount {
+/// #         user_id: "u".into(),
+/// #         session_id: None,
+/// #         properties: Box::new(CreateAccountProperties {
+/// #             billing_address: address(),
+/// #             payment_methods: Some(vec![PaymentMethod {
+/// #                 card_bin: Some("542486".into()),
+/// #                 extra: extra(),
+/// #                 ..Default::default()
+/// #             }]),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         }),
+/// #     },
+/// #     Event::CreateContent {
+/// #         user_id: "u".into(),
+/// #         content_id: "c".into(),
+/// #         content: Content::Comment(CommentProperties {
+/// #             body: Some("b".into()),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         }),
+/// #         properties: ContentProperties {
+/// #             ip: Some("255.255.255.0".into()),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         },
+/// #     },
+/// #     Event::CreateOrder {
+/// #         user_id: Some("u".into()),
+/// #         properties: OrderProperties {
+/// #             shipping_address: address(),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         },
+/// #     },
+/// #     Event::FlagContent {
+/// #         user_id: "u".into(),
+/// #         content_id: "c".into(),
+/// #         properties: FlagContentProperties::default(),
+/// #     },
+/// #     Event::LinkSessionToUser {
+/// #         session_id: "s".into(),
+/// #         user_id: "u".into(),
+/// #     },
+/// #     Event::Label {
+/// #         is_fraud: true,
+/// #         abuse_type: AbuseType::PromoAbuse,
+/// #         properties: LabelProperties {
+/// #             description: Some("d".into()),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         },
+/// #     },
+/// #     Event::Logout {
+/// #         user_id: "u".into(),
+/// #         properties: LogoutProperties::default(),
+/// #     },
+/// #     Event::OrderStatus {
+/// #         user_id: "u".into(),
+/// #         order_id: "o".into(),
+/// #         order_status: OrderStatus::Approved,
+/// #         properties: OrderStatusProperties::default(),
+/// #     },
+/// #     Event::RemoveItemFromCart {
+/// #         user_id: Some("u".into()),
+/// #         session_id: None,
+/// #         properties: RemoveItemFromCartProperties::default(),
+/// #     },
+/// #     Event::SecurityNotification {
+/// #         user_id: "u".into(),
+/// #         session_id: "s".into(),
+/// #         notification_status: "$sent".into(),
+/// #         properties: SecurityNotificationProperties::default(),
+/// #     },
+/// #     Event::Transaction {
+/// #         user_id: Some("u".into()),
+/// #         amount: Micros::from_raw(5_000_000),
+/// #         currency_code: "USD".into(),
+/// #         properties: Box::new(TransactionProperties {
+/// #             session_id: Some("s".into()),
+/// #             extra: extra(),
+/// #             ..Default::default()
+/// #         }),
+/// #     },
+/// #     Event::UpdateAccount {
+/// #         user_id: "u".into(),
+/// #         properties: Box::default(),
+/// #     },
+/// #     Event::UpdateContent {
+/// #         user_id: "u".into(),
+/// #         content_id: "c".into(),
+/// #         content: Content::Listing(ListingProperties {
+/// #             subject: Some("s".into()),
+/// #             contact_address: address(),
+/// #             ..Default::default()
+/// #         }),
+/// #         properties: ContentProperties::default(),
+/// #     },
+/// #     Event::UpdateOrder {
+/// #         user_id: Some("u".into()),
+/// #         properties: OrderProperties::default(),
+/// #     },
+/// #     Event::UpdatePassword {
+/// #         user_id: "u".into(),
+/// #         reason: UpdatePasswordReason::UserUpdate,
+/// #         status: UpdatePasswordStatus::Success,
+/// #         properties: UpdatePasswordProperties::default(),
+/// #     },
+/// #     Event::Verification {
+/// #         user_id: "u".into(),
+/// #         session_id: "s".into(),
+/// #         status: VerificationStatus::Success,
+/// #         properties: VerificationProperties::default(),
+/// #     },
+/// # ];
+/// #
+/// # for event in events {
+/// #     let json = serde_json::to_string(&event).unwrap();
+/// #     assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event, "{}", json);
+/// #     assert_eq!(
+/// #         serde_json::to_value(&event).unwrap()["$type"],
+/// #         event.reserved_type(),
+/// #         "{}",
+/// #         json
+/// #     );
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "$type")]
 pub enum Event {
     /// Use `AddItemToCart` to record when a user adds an item to their shopping cart or list.
@@ -37,12 +239,16 @@ pub enum Event {
         /// The user's internal ID. Users without an assigned `user_id` will not show up in
         /// the console. Find valid `user_id` values [here].
         ///
+        /// Note: required if no session ID is provided.
+        ///
         /// [here]: https://sift.com/developers/docs/curl/events-api/fields
-        #[serde(rename = "$user_id")]
-        user_id: String,
+        #[serde(rename = "$user_id", default, skip_serializing_if = "Option::is_none")]
+        user_id: Option<String>,
 
         /// The user's current session ID, used to tie a user's action before and after log in or
         /// account creation.
+        ///
+        /// Note: required if no User ID is provided.
         #[serde(rename = "$session_id")]
         session_id: Option<String>,
 
@@ -213,9 +419,11 @@ pub enum Event {
         ///
         /// Find valid `user_id` values [here].
         ///
+        /// Note: required if no session ID is provided in the properties.
+        ///
         /// [here]: https://sift.com/developers/docs/curl/events-api/fields
-        #[serde(rename = "$user_id")]
-        user_id: String,
+        #[serde(rename = "$user_id", default, skip_serializing_if = "Option::is_none")]
+        user_id: Option<String>,
 
         /// Optional properties for the `CreateOrder` event.
         ///
@@ -413,9 +621,11 @@ pub enum Event {
         ///
         /// Note that user IDs are case sensitive. Find valid `user_id` values [here].
         ///
+        /// Note: required if no session ID is provided.
+        ///
         /// [here]: https://sift.com/developers/docs/curl/events-api/fields
-        #[serde(rename = "$user_id")]
-        user_id: String,
+        #[serde(rename = "$user_id", default, skip_serializing_if = "Option::is_none")]
+        user_id: Option<String>,
 
         /// Optional properties for the `RemoveItemFromCart` event
         ///
@@ -471,9 +681,11 @@ pub enum Event {
         ///
         /// Note that user IDs are case sensitive. Find valid `user_id` values [here].
         ///
+        /// Note: required if no session ID is provided in the properties.
+        ///
         /// [here]: https://sift.com/developers/docs/curl/events-api/fields
-        #[serde(rename = "$user_id")]
-        user_id: String,
+        #[serde(rename = "$user_id", default, skip_serializing_if = "Option::is_none")]
+        user_id: Option<String>,
 
         /// Total transaction amount in micros in the base unit of the `currency_code`.
         #[serde(rename = "$amount")]
@@ -485,7 +697,7 @@ pub enum Event {
         ///
         /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
         #[serde(rename = "$currency_code")]
-        currency_code: String,
+        currency_code: CurrencyCode,
 
         /// Optional properties for the `Transaction` event
         ///
@@ -563,9 +775,11 @@ pub enum Event {
         /// The user's internal ID. Users without an assigned `user_id` will not show up in
         /// the console. Find valid `user_id` values [here].
         ///
+        /// Note: required if no session ID is provided in the properties.
+        ///
         /// [here]: https://sift.com/developers/docs/curl/events-api/fields
-        #[serde(rename = "$user_id")]
-        user_id: String,
+        #[serde(rename = "$user_id", default, skip_serializing_if = "Option::is_none")]
+        user_id: Option<String>,
 
         /// Optional properties for the `UpdateOrder` event.
         ///
@@ -637,10 +851,432 @@ pub enum Event {
     },
 }
 
+impl Event {
+    /// A [Event::Transaction] for `amount` in `currency`.
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::events::{CurrencyCode, Event, Micros, TransactionProperties};
+    ///
+    /// let event = Event::transaction(
+    ///     "billy_jones_301",
+    ///     Micros::from_base_units(50_634),
+    ///     CurrencyCode::new("USD").unwrap(),
+    ///     TransactionProperties::default(),
+    /// );
+    ///
+    /// let json = serde_json::to_value(&event).unwrap();
+    /// assert_eq!(json["$amount"], json!(506_340_000));
+    /// assert_eq!(json["$currency_code"], json!("USD"));
+    /// ```
+    pub fn transaction(
+        user_id: impl Into<String>,
+        amount: Micros,
+        currency: CurrencyCode,
+        properties: TransactionProperties,
+    ) -> Self {
+        Event::Transaction {
+            user_id: Some(user_id.into()),
+            amount,
+            currency_code: currency,
+            properties: Box::new(properties),
+        }
+    }
+
+    /// The `$user_id` of the event, if it has one.
+    ///
+    /// ```
+    /// use sift_science::events::{AddItemToCartProperties, Event, LoginProperties};
+    ///
+    /// let event = Event::Login {
+    ///     user_id: "billy_jones_301".into(),
+    ///     session_id: None,
+    ///     properties: LoginProperties::default(),
+    /// };
+    /// assert_eq!(event.user_id(), Some("billy_jones_301"));
+    ///
+    /// let event = Event::AddItemToCart {
+    ///     user_id: None,
+    ///     session_id: Some("gigtleqddo84l8cm15qe4il".into()),
+    ///     properties: AddItemToCartProperties::default(),
+    /// };
+    /// assert_eq!(event.user_id(), None);
+    /// ```
+    pub fn user_id(&self) -> Option<&str> {
+        match self {
+            Event::AddPromotion { user_id, .. }
+            | Event::ContentStatus { user_id, .. }
+            | Event::CreateAccount { user_id, .. }
+            | Event::CreateContent { user_id, .. }
+            | Event::FlagContent { user_id, .. }
+            | Event::LinkSessionToUser { user_id, .. }
+            | Event::Login { user_id, .. }
+            | Event::Logout { user_id, .. }
+            | Event::OrderStatus { user_id, .. }
+            | Event::SecurityNotification { user_id, .. }
+            | Event::UpdateAccount { user_id, .. }
+            | Event::UpdateContent { user_id, .. }
+            | Event::UpdatePassword { user_id, .. }
+            | Event::Verification { user_id, .. } => Some(user_id),
+            Event::AddItemToCart { user_id, .. }
+            | Event::CreateOrder { user_id, .. }
+            | Event::RemoveItemFromCart { user_id, .. }
+            | Event::Transaction { user_id, .. }
+            | Event::UpdateOrder { user_id, .. } => user_id.as_deref(),
+            Event::Chargeback { properties, .. } => properties.user_id.as_deref(),
+            Event::Label { .. } => None,
+        }
+    }
+
+    /// The reserved `$type` of the event, e.g. `"$create_order"`, without serializing it.
+    ///
+    /// Useful for tagging logs and metrics by event type.
+    ///
+    /// ```
+    /// use sift_science::events::{Event, OrderProperties};
+    ///
+    /// let event = Event::CreateOrder {
+    ///     user_id: Some("billy_jones_301".into()),
+    ///     properties: OrderProperties::default(),
+    /// };
+    /// assert_eq!(event.reserved_type(), "$create_order");
+    /// assert_eq!(
+    ///     serde_json::to_value(&event).unwrap()["$type"],
+    ///     event.reserved_type()
+    /// );
+    /// ```
+    pub fn reserved_type(&self) -> &'static str {
+        match self {
+            Event::AddItemToCart { .. } => "$add_item_to_cart",
+            Event::AddPromotion { .. } => "$add_promotion",
+            Event::Chargeback { .. } => "$chargeback",
+            Event::ContentStatus { .. } => "$content_status",
+            Event::CreateAccount { .. } => "$create_account",
+            Event::CreateContent { .. } => "$create_content",
+            Event::CreateOrder { .. } => "$create_order",
+            Event::FlagContent { .. } => "$flag_content",
+            Event::LinkSessionToUser { .. } => "$link_session_to_user",
+            Event::Label { .. } => "$label",
+            Event::Login { .. } => "$login",
+            Event::Logout { .. } => "$logout",
+            Event::OrderStatus { .. } => "$order_status",
+            Event::RemoveItemFromCart { .. } => "$remove_item_from_cart",
+            Event::SecurityNotification { .. } => "$security_notification",
+            Event::Transaction { .. } => "$transaction",
+            Event::UpdateAccount { .. } => "$update_account",
+            Event::UpdateContent { .. } => "$update_content",
+            Event::UpdateOrder { .. } => "$update_order",
+            Event::UpdatePassword { .. } => "$update_password",
+            Event::Verification { .. } => "$verification",
+        }
+    }
+
+    /// Checks the event for field combinations Sift would otherwise silently drop.
+    ///
+    /// Returns an [Error::Validation] when both `$browser` and `$app` are set, as they cannot be
+    /// used in conjunction, when an event that may be sent for an anonymous session, such as
+    /// `AddItemToCart` or `CreateOrder`, has neither a `$user_id` nor a `$session_id`, or when a
+    /// `Transaction` sets a `$transfer_recipient_user_id` without being a
+    /// [TransactionType::Transfer], or a `$decline_category` without being a
+    /// [TransactionStatus::Failure].
+    ///
+    /// ```
+    /// use sift_science::{
+    ///     events::{
+    ///         AddItemToCartProperties, App, Browser, Event, LoginProperties, Micros,
+    ///         TransactionProperties,
+    ///     },
+    ///     Error,
+    /// };
+    ///
+    /// let browser = || Browser {
+    ///     user_agent: "Mozilla/5.0".into(),
+    ///     accept_language: None,
+    ///     content_language: None,
+    ///     extra: None,
+    /// };
+    /// let app = || App {
+    ///     os: Some("iOS".into()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let login = |browser, app| Event::Login {
+    ///     user_id: "billy_jones_301".into(),
+    ///     session_id: None,
+    ///     properties: LoginProperties {
+    ///         browser,
+    ///         app,
+    ///         ..Default::default()
+    ///     },
+    /// };
+    /// assert!(matches!(
+    ///     login(Some(browser()), Some(app())).validate(),
+    ///     Err(Error::Validation(_))
+    /// ));
+    /// assert!(login(Some(browser()), None).validate().is_ok());
+    /// assert!(login(None, Some(app())).validate().is_ok());
+    /// assert!(login(None, None).validate().is_ok());
+    ///
+    /// let add_item_to_cart = |browser, app| Event::AddItemToCart {
+    ///     user_id: Some("billy_jones_301".into()),
+    ///     session_id: None,
+    ///     properties: AddItemToCartProperties {
+    ///         browser,
+    ///         app,
+    ///         ..Default::default()
+    ///     },
+    /// };
+    /// assert!(add_item_to_cart(Some(browser()), Some(app())).validate().is_err());
+    /// assert!(add_item_to_cart(Some(browser()), None).validate().is_ok());
+    /// assert!(add_item_to_cart(None, Some(app())).validate().is_ok());
+    /// assert!(add_item_to_cart(None, None).validate().is_ok());
+    ///
+    /// let transaction = |browser, app| Event::Transaction {
+    ///     user_id: Some("billy_jones_301".into()),
+    ///     amount: Micros::from_base_units(500),
+    ///     currency_code: "USD".into(),
+    ///     properties: Box::new(TransactionProperties {
+    ///         browser,
+    ///         app,
+    ///         ..Default::default()
+    ///     }),
+    /// };
+    /// assert!(transaction(Some(browser()), Some(app())).validate().is_err());
+    /// assert!(transaction(Some(browser()), None).validate().is_ok());
+    /// assert!(transaction(None, Some(app())).validate().is_ok());
+    /// assert!(transaction(None, None).validate().is_ok());
+    /// ```
+    ///
+    /// Shopping and order events can be sent for a session before the user logs in:
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::{
+    ///     events::{Event, OrderProperties, RemoveItemFromCartProperties},
+    ///     Error,
+    /// };
+    ///
+    /// let remove_item_from_cart = Event::RemoveItemFromCart {
+    ///     user_id: None,
+    ///     session_id: Some("gigtleqddo84l8cm15qe4il".into()),
+    ///     properties: RemoveItemFromCartProperties::default(),
+    /// };
+    /// assert!(remove_item_from_cart.validate().is_ok());
+    /// assert_eq!(
+    ///     serde_json::to_value(&remove_item_from_cart).unwrap(),
+    ///     json!({
+    ///         "$type": "$remove_item_from_cart",
+    ///         "$session_id": "gigtleqddo84l8cm15qe4il",
+    ///     })
+    /// );
+    ///
+    /// let create_order = Event::CreateOrder {
+    ///     user_id: None,
+    ///     properties: OrderProperties::builder()
+    ///         .session_id("gigtleqddo84l8cm15qe4il")
+    ///         .build(),
+    /// };
+    /// assert!(create_order.validate().is_ok());
+    ///
+    /// let remove_item_from_cart = Event::RemoveItemFromCart {
+    ///     user_id: None,
+    ///     session_id: None,
+    ///     properties: RemoveItemFromCartProperties::default(),
+    /// };
+    /// assert!(matches!(
+    ///     remove_item_from_cart.validate(),
+    ///     Err(Error::Validation(_))
+    /// ));
+    /// ```
+    ///
+    /// A crypto transfer to another user's wallet:
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use sift_science::{
+    ///     events::{
+    ///         DigitalOrder, DigitalOrderAssetType, DigitalOrderType, Event, Micros,
+    ///         TransactionProperties, TransactionType,
+    ///     },
+    ///     Error,
+    /// };
+    ///
+    /// let transfer = |transaction_type| {
+    ///     Event::transaction(
+    ///         "billy_jones_301",
+    ///         Micros::from_base_units(50_000),
+    ///         "USD".into(),
+    ///         TransactionProperties {
+    ///             transaction_type,
+    ///             transfer_recipient_user_id: Some("sally_smith_302".into()),
+    ///             digital_orders: Some(vec![DigitalOrder {
+    ///                 digital_asset: "BTC".into(),
+    ///                 pair: Some("BTC_USD".into()),
+    ///                 asset_type: Some(DigitalOrderAssetType::Crypto),
+    ///                 order_type: Some(DigitalOrderType::Market),
+    ///                 volume: Some("0.0125".into()),
+    ///                 extra: None,
+    ///             }]),
+    ///             receiver_wallet_address: Some("bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh".into()),
+    ///             receiver_external_address: Some(true),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    /// };
+    ///
+    /// let event = transfer(Some(TransactionType::Transfer));
+    /// assert!(event.validate().is_ok());
+    ///
+    /// let json = serde_json::to_value(&event).unwrap();
+    /// assert_eq!(json["$transaction_type"], "$transfer");
+    /// assert_eq!(json["$transfer_recipient_user_id"], "sally_smith_302");
+    /// assert_eq!(
+    ///     json["$digital_orders"],
+    ///     json!([{
+    ///         "$digital_asset": "BTC",
+    ///         "$pair": "BTC_USD",
+    ///         "$asset_type": "$crypto",
+    ///         "$order_type": "$market",
+    ///         "$volume": "0.0125",
+    ///     }])
+    /// );
+    /// assert_eq!(
+    ///     json["$receiver_wallet_address"],
+    ///     "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh"
+    /// );
+    /// assert_eq!(json["$receiver_external_address"], true);
+    /// assert_eq!(serde_json::from_value::<Event>(json).unwrap(), event);
+    ///
+    /// assert!(matches!(
+    ///     transfer(Some(TransactionType::Sale)).validate(),
+    ///     Err(Error::Validation(_))
+    /// ));
+    /// assert!(transfer(None).validate().is_err());
+    /// ```
+    ///
+    /// A decline category is only sent for failed transactions:
+    ///
+    /// ```
+    /// use sift_science::{
+    ///     events::{
+    ///         DeclineCategory, Event, Micros, TransactionProperties, TransactionStatus,
+    ///     },
+    ///     Error,
+    /// };
+    ///
+    /// let declined = |transaction_status| {
+    ///     Event::transaction(
+    ///         "billy_jones_301",
+    ///         Micros::from_base_units(50_000),
+    ///         "USD".into(),
+    ///         TransactionProperties {
+    ///             transaction_status,
+    ///             decline_category: Some(DeclineCategory::InsufficientFunds),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    /// };
+    ///
+    /// assert!(declined(Some(TransactionStatus::Failure)).validate().is_ok());
+    /// for transaction_status in [
+    ///     Some(TransactionStatus::Success),
+    ///     Some(TransactionStatus::Pending),
+    ///     None,
+    /// ] {
+    ///     assert!(matches!(
+    ///         declined(transaction_status).validate(),
+    ///         Err(Error::Validation(_))
+    ///     ));
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        let anonymous = match self {
+            Event::AddItemToCart {
+                user_id,
+                session_id,
+                ..
+            }
+            | Event::RemoveItemFromCart {
+                user_id,
+                session_id,
+                ..
+            } => user_id.is_none() && session_id.is_none(),
+            Event::CreateOrder {
+                user_id,
+                properties,
+            }
+            | Event::UpdateOrder {
+                user_id,
+                properties,
+            } => user_id.is_none() && properties.session_id.is_none(),
+            Event::Transaction {
+                user_id,
+                properties,
+                ..
+            } => user_id.is_none() && properties.session_id.is_none(),
+            _ => false,
+        };
+
+        if anonymous {
+            return Err(Error::Validation(format!(
+                "{} requires a $user_id or a $session_id",
+                self.reserved_type()
+            )));
+        }
+
+        if let Event::Transaction { properties, .. } = self {
+            if properties.transfer_recipient_user_id.is_some()
+                && properties.transaction_type != Some(TransactionType::Transfer)
+            {
+                return Err(Error::Validation(
+                    "$transfer_recipient_user_id requires a $transaction_type of $transfer".into(),
+                ));
+            }
+
+            if properties.decline_category.is_some()
+                && properties.transaction_status != Some(TransactionStatus::Failure)
+            {
+                return Err(Error::Validation(
+                    "$decline_category requires a $transaction_status of $failure".into(),
+                ));
+            }
+        }
+
+        let (browser, app) = match self {
+            Event::AddItemToCart { properties, .. } => (&properties.browser, &properties.app),
+            Event::AddPromotion { properties, .. } => (&properties.browser, &properties.app),
+            Event::ContentStatus { properties, .. } => (&properties.browser, &properties.app),
+            Event::CreateAccount { properties, .. } => (&properties.browser, &properties.app),
+            Event::CreateContent { properties, .. } => (&properties.browser, &properties.app),
+            Event::Login { properties, .. } => (&properties.browser, &properties.app),
+            Event::Logout { properties, .. } => (&properties.browser, &properties.app),
+            Event::OrderStatus { properties, .. } => (&properties.browser, &properties.app),
+            Event::RemoveItemFromCart { properties, .. } => (&properties.browser, &properties.app),
+            Event::SecurityNotification { properties, .. } => {
+                (&properties.browser, &properties.app)
+            }
+            Event::Transaction { properties, .. } => (&properties.browser, &properties.app),
+            Event::UpdateAccount { properties, .. } => (&properties.browser, &properties.app),
+            Event::UpdateContent { properties, .. } => (&properties.browser, &properties.app),
+            Event::UpdatePassword { properties, .. } => (&properties.browser, &properties.app),
+            Event::Verification { properties, .. } => (&properties.browser, &properties.app),
+            _ => return Ok(()),
+        };
+
+        if browser.is_some() && app.is_some() {
+            return Err(Error::Validation(
+                "$browser and $app cannot be used in conjunction".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Types of content Sift supports
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content>
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum Content {
     /// Used to tell Sift whenever a user posts into the comment section your
     /// site.
@@ -684,7 +1320,7 @@ pub enum Content {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content/comment>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct CommentProperties {
     /// The text content of the comment.
     #[serde(rename = "$body")]
@@ -713,11 +1349,11 @@ pub struct CommentProperties {
     /// The list of images shared by the user with their comment.
     ///
     /// It includes images pasted inline or attached separately.
-    #[serde(rename = "$images")]
+    #[serde(rename = "$images", skip_serializing_if = "is_none_or_empty")]
     pub images: Option<Vec<Image>>,
 
     /// Any extra non-reserved fields to be recorded with the comment.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -728,7 +1364,7 @@ pub struct CommentProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content/listing>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ListingProperties {
     /// The subject of the listing.
     #[serde(rename = "$subject")]
@@ -758,17 +1394,17 @@ pub struct ListingProperties {
     /// listing. You can pass one or more addresses that are associated with
     /// your listing. Pass as much information as you have. Partial addresses
     /// such as just the city and state are fine if that's all you have.
-    #[serde(rename = "$locations")]
+    #[serde(rename = "$locations", skip_serializing_if = "is_none_or_empty")]
     pub locations: Option<Vec<Address>>,
 
     /// The items array represents physical or digital items listed by the user.
-    #[serde(rename = "$listed_items")]
+    #[serde(rename = "$listed_items", skip_serializing_if = "is_none_or_empty")]
     pub listed_items: Option<Vec<Item>>,
 
     /// The list of images shared by the user with their listing.
     ///
     /// It includes images pasted inline or attached separately.
-    #[serde(rename = "$images")]
+    #[serde(rename = "$images", skip_serializing_if = "is_none_or_empty")]
     pub images: Option<Vec<Image>>,
 
     /// The time when the listing will expire.
@@ -777,21 +1413,43 @@ pub struct ListingProperties {
     /// close 14 days from date of posting).
     #[serde(
         rename = "$expiration_time",
+        default,
         deserialize_with = "deserialize_opt_ms",
         serialize_with = "serialize_opt_ms"
     )]
     pub expiration_time: Option<SystemTime>,
 
     /// Any extra non-reserved fields to be recorded with the listing.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
 /// Used to represent a message exchanged between users of your service.
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content/message>
+///
+/// Empty lists, like content lists of other types, are left out rather than sent empty:
+///
+/// ```
+/// use sift_science::events::MessageProperties;
+///
+/// let message = |recipient_user_ids: Vec<&str>| MessageProperties {
+///     body: Some("Let's meet at 5pm".into()),
+///     recipient_user_ids: Some(recipient_user_ids.into_iter().map(Into::into).collect()),
+///     ..Default::default()
+/// };
+///
+/// let json = serde_json::to_value(message(vec![])).unwrap();
+/// assert!(json.get("$recipient_user_ids").is_none());
+///
+/// let json = serde_json::to_value(message(vec!["fy9h989sjphh71"])).unwrap();
+/// assert_eq!(
+///     json["$recipient_user_ids"],
+///     serde_json::json!(["fy9h989sjphh71"])
+/// );
+/// ```
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct MessageProperties {
     /// The user-supplied subject of the message.
     #[serde(rename = "$subject")]
@@ -812,17 +1470,20 @@ pub struct MessageProperties {
     pub root_content_id: Option<String>,
 
     /// The user ids of the recipients of the message.
-    #[serde(rename = "$recipient_user_ids")]
+    #[serde(
+        rename = "$recipient_user_ids",
+        skip_serializing_if = "is_none_or_empty"
+    )]
     pub recipient_user_ids: Option<Vec<String>>,
 
     /// The list of images shared by the user with their message.
     ///
     /// It includes images pasted inline or attached separately.
-    #[serde(rename = "$images")]
+    #[serde(rename = "$images", skip_serializing_if = "is_none_or_empty")]
     pub images: Option<Vec<Image>>,
 
     /// Any extra non-reserved fields to be recorded with the message.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -833,7 +1494,7 @@ pub struct MessageProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content/post>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct PostProperties {
     /// The user-supplied subject of the post.
     #[serde(rename = "$subject")]
@@ -864,19 +1525,19 @@ pub struct PostProperties {
     /// associated with your post. Pass as much information as you have. Partial
     /// addresses such as just the city and state are fine if that's all you
     /// have.
-    #[serde(rename = "$locations")]
+    #[serde(rename = "$locations", skip_serializing_if = "is_none_or_empty")]
     pub locations: Option<Vec<Address>>,
 
     /// The category or categories you associate with the posting.
     ///
     /// For example, a blog post might be categorized as ["Family", "Travel"].
-    #[serde(rename = "$categories")]
+    #[serde(rename = "$categories", skip_serializing_if = "is_none_or_empty")]
     pub categories: Option<Vec<String>>,
 
     /// The list of images shared by the user with their post.
     ///
     /// It includes images pasted inline or attached separately.
-    #[serde(rename = "$images")]
+    #[serde(rename = "$images", skip_serializing_if = "is_none_or_empty")]
     pub images: Option<Vec<Image>>,
 
     /// The time when the post will expire.
@@ -884,13 +1545,14 @@ pub struct PostProperties {
     /// Only set if the post is time bound in some way.
     #[serde(
         rename = "$expiration_time",
+        default,
         deserialize_with = "deserialize_opt_ms",
         serialize_with = "serialize_opt_ms"
     )]
     pub expiration_time: Option<SystemTime>,
 
     /// Any extra non-reserved fields to be recorded with the post.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -900,7 +1562,7 @@ pub struct PostProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content/profile>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ProfileProperties {
     /// The text content of the profile.
     #[serde(rename = "$body")]
@@ -923,18 +1585,18 @@ pub struct ProfileProperties {
     /// The list of images shared by the user with their profile.
     ///
     /// It includes images pasted inline or attached separately.
-    #[serde(rename = "$images")]
+    #[serde(rename = "$images", skip_serializing_if = "is_none_or_empty")]
     pub images: Option<Vec<Image>>,
 
     /// The category or categories you associate with the profile.
     ///
     /// For example, a profile on a services marketplace might be categorized as
     /// `["Photographer", "Weddings"]`.
-    #[serde(rename = "$categories")]
+    #[serde(rename = "$categories", skip_serializing_if = "is_none_or_empty")]
     pub categories: Option<Vec<String>>,
 
     /// Any extra non-reserved fields to be recorded with the profile.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -943,7 +1605,7 @@ pub struct ProfileProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content/review>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ReviewProperties {
     /// The user-supplied subject of the review.
     #[serde(rename = "$subject")]
@@ -965,7 +1627,7 @@ pub struct ReviewProperties {
     /// You can pass one or more addresses that are associated with your review.
     /// Pass as much information as you have. Partial addresses such as just the
     /// city and state are fine if that's all you have.
-    #[serde(rename = "$locations")]
+    #[serde(rename = "$locations", skip_serializing_if = "is_none_or_empty")]
     pub locations: Option<Vec<Address>>,
 
     /// An Item object representing the item being reviewed.
@@ -986,11 +1648,11 @@ pub struct ReviewProperties {
     /// The list of images shared by the user with their review.
     ///
     /// It includes images pasted inline or attached separately.
-    #[serde(rename = "$images")]
+    #[serde(rename = "$images", skip_serializing_if = "is_none_or_empty")]
     pub images: Option<Vec<Image>>,
 
     /// Any extra non-reserved fields to be recorded with the review.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -998,7 +1660,7 @@ pub struct ReviewProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/add-item-to-cart>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct AddItemToCartProperties {
     /// The product item added to cart.
     ///
@@ -1006,6 +1668,12 @@ pub struct AddItemToCartProperties {
     #[serde(rename = "$item")]
     pub item: Option<Item>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to add the item to cart.
     ///
     /// Represented by the [Browser] object. Use this field if the client is a browser.
@@ -1043,7 +1711,7 @@ pub struct AddItemToCartProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1051,12 +1719,18 @@ pub struct AddItemToCartProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/add-promotion>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct AddPromotionProperties {
     /// Contains all promotions that have been newly applied to the referenced user.
     #[serde(rename = "$promotions")]
     pub promotions: Option<Vec<Promotion>>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to add the promotion
     ///
     /// Represented by the [Browser] object. Use this field if the client is a browser.
@@ -1094,7 +1768,7 @@ pub struct AddPromotionProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1102,7 +1776,7 @@ pub struct AddPromotionProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/chargeback>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ChargebackProperties {
     /// The user's account ID according to your systems.
     ///
@@ -1120,16 +1794,66 @@ pub struct ChargebackProperties {
     pub chargeback_reason: Option<ChargebackReason>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
 /// Properties of the `ContentStatus` event.
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/content-status>
+///
+/// ```
+/// use serde_json::json;
+/// use sift_science::events::{ContentStatus, ContentStatusProperties, DecisionSource, Event};
+///
+/// let event = Event::ContentStatus {
+///     user_id: "billy_jones_301".into(),
+///     content_id: "9671500641".into(),
+///     status: ContentStatus::Paused,
+///     properties: ContentStatusProperties {
+///         reason: Some("spam".into()),
+///         source: Some(DecisionSource::ManualReview),
+///         analyst: Some("someone@your-site.com".into()),
+///         webhook_id: Some("3ff1082a4aea8d0c58e3643ddb7a5bb87ffffeb2492dca33".into()),
+///         ..Default::default()
+///     },
+/// };
+///
+/// let value = serde_json::to_value(&event).unwrap();
+/// assert_eq!(value["$reason"], json!("spam"));
+/// assert_eq!(value["$source"], json!("$manual_review"));
+/// assert_eq!(value["$analyst"], json!("someone@your-site.com"));
+/// assert_eq!(
+///     value["$webhook_id"],
+///     json!("3ff1082a4aea8d0c58e3643ddb7a5bb87ffffeb2492dca33")
+/// );
+/// ```
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ContentStatusProperties {
+    /// The reason for the status change.
+    #[serde(rename = "$reason")]
+    pub reason: Option<String>,
+
+    /// The source of a decision.
+    #[serde(rename = "$source")]
+    pub source: Option<DecisionSource>,
+
+    /// The analyst who made the decision, if manual.
+    #[serde(rename = "$analyst")]
+    pub analyst: Option<String>,
+
+    /// An alternative to using `source` and `analyst`, this is the ID of the Sift Action webhook
+    /// that triggered the status change.
+    #[serde(rename = "$webhook_id")]
+    pub webhook_id: Option<String>,
+
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to set the content status.
     /// Represented by the [Browser] object. Use this field if the client is a
     /// browser.
@@ -1163,15 +1887,29 @@ pub struct ContentStatusProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
 /// Properties of the `CreateAccount` event
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-account>
+///
+/// ```
+/// use sift_science::events::CreateAccountProperties;
+///
+/// let properties = CreateAccountProperties {
+///     ip: Some("128.148.1.135".into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&properties).unwrap(),
+///     serde_json::json!({ "$ip": "128.148.1.135" })
+/// );
+/// ```
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct CreateAccountProperties {
     /// Email of the user creating the account. Note: If the user's email is also their account
     /// ID in your system, set **both** the `user_id` and `user_email` fields to their email
@@ -1225,6 +1963,12 @@ pub struct CreateAccountProperties {
     #[serde(rename = "$social_sign_on_type")]
     pub social_sign_on_type: Option<SocialSignOn>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to create the account. Represented by the
     /// [Browser] object. Use this field if the client is a browser.
     ///
@@ -1262,7 +2006,7 @@ pub struct CreateAccountProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1271,7 +2015,7 @@ pub struct CreateAccountProperties {
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-content>
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/update-content>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ContentProperties {
     /// The user's current session ID, used to tie a user's action before and
     /// after login or account creation.
@@ -1322,7 +2066,7 @@ pub struct ContentProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1330,8 +2074,22 @@ pub struct ContentProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/create-order>
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/update-order>
+///
+/// ```
+/// use sift_science::events::OrderProperties;
+///
+/// let properties = OrderProperties {
+///     order_id: Some("ORDER-28168441".into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&properties).unwrap(),
+///     serde_json::json!({ "$order_id": "ORDER-28168441" })
+/// );
+/// ```
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct OrderProperties {
     /// The user's current session ID, used to tie a user's action before and after login or
     /// account creation.
@@ -1358,6 +2116,12 @@ pub struct OrderProperties {
     #[serde(rename = "$verification_phone_number")]
     pub verification_phone_number: Option<String>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// Total transaction amount in micros in the base unit of the $currency_code.
     ///
     /// 1 cent = 10,000 micros. $1.23 USD = 123 cents = 1,230,000 micros. For currencies without
@@ -1371,7 +2135,7 @@ pub struct OrderProperties {
     ///
     /// [ISO-4217]: http://en.wikipedia.org/wiki/ISO_4217
     #[serde(rename = "$currency_code")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 
     /// The billing address as entered by the user.
     #[serde(rename = "$billing_address")]
@@ -1470,10 +2234,10 @@ pub struct OrderProperties {
     /// A digital order represents a digital asset which can be part of a cryptocurrency or digital
     /// asset transaction. Note: cannot be used in conjunction with `items` or `bookings`.
     #[serde(rename = "$digital_orders")]
-    pub digital_orders: Vec<DigitalOrder>,
+    pub digital_orders: Option<Vec<DigitalOrder>>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1481,7 +2245,7 @@ pub struct OrderProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/flag-content>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct FlagContentProperties {
     /// The account ID of the user who is flagging the content.
     ///
@@ -1494,7 +2258,7 @@ pub struct FlagContentProperties {
     pub reason: Option<ContentFlagReason>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1502,7 +2266,7 @@ pub struct FlagContentProperties {
 ///
 /// <https://sift.com/developers/docs/curl/labels-api>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct LabelProperties {
     /// Freeform text description of the user and/or incident triggering the label.
     ///
@@ -1523,7 +2287,7 @@ pub struct LabelProperties {
     pub analyst: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1531,7 +2295,7 @@ pub struct LabelProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/login>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct LoginProperties {
     /// Use `login_status` to represent the success or failure of the login attempt.
     #[serde(rename = "$login_status")]
@@ -1598,7 +2362,7 @@ pub struct LoginProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1606,8 +2370,14 @@ pub struct LoginProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/logout>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct LogoutProperties {
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to logout.  Represented by the [Browser] object.
     /// Use this field if the client is a browser.
     ///
@@ -1639,7 +2409,7 @@ pub struct LogoutProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1647,7 +2417,7 @@ pub struct LogoutProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/order-status>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct OrderStatusProperties {
     /// The reason for a cancellation.
     #[serde(rename = "$reason")]
@@ -1670,6 +2440,12 @@ pub struct OrderStatusProperties {
     #[serde(rename = "$description")]
     pub description: Option<String>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to add the item to cart.
     ///
     /// Represented by the [Browser] struct. Use this field if the client is a browser. Note: cannot
@@ -1701,7 +2477,7 @@ pub struct OrderStatusProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1709,12 +2485,18 @@ pub struct OrderStatusProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/remove-item-from-cart>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct RemoveItemFromCartProperties {
     /// The product item removed from cart.
     #[serde(rename = "$item")]
     pub item: Option<Item>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to remove the item from cart.
     ///
     /// Represented by the [Browser] struct. Use this field if the client is a browser. Note: cannot
@@ -1746,7 +2528,7 @@ pub struct RemoveItemFromCartProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1754,7 +2536,7 @@ pub struct RemoveItemFromCartProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/security-notification>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct SecurityNotificationProperties {
     /// The type of notification issued.
     #[serde(rename = "$notification_type")]
@@ -1771,6 +2553,12 @@ pub struct SecurityNotificationProperties {
     #[serde(rename = "$notified_value")]
     pub notified_value: Option<String>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser.
     ///
     /// Represented by the [Browser] struct. Use this field if the client is a browser. Note: cannot
@@ -1802,15 +2590,44 @@ pub struct SecurityNotificationProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
 /// Properties of the `Transaction` event.
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/transaction>
+///
+/// ```
+/// use sift_science::events::{MerchantProfile, TransactionProperties};
+///
+/// let properties = TransactionProperties::builder()
+///     .ip("128.148.1.135")
+///     .build();
+///
+/// assert_eq!(
+///     serde_json::to_value(&properties).unwrap(),
+///     serde_json::json!({ "$ip": "128.148.1.135" })
+/// );
+///
+/// let properties = TransactionProperties {
+///     merchant_initiated_transaction: Some(true),
+///     merchant_profile: Some(MerchantProfile {
+///         merchant_id: "12345".into(),
+///         merchant_category_code: Some("9876".into()),
+///         merchant_name: "ABC Merchant".into(),
+///         merchant_address: None,
+///         extra: None,
+///     }),
+///     ..Default::default()
+/// };
+///
+/// let json = serde_json::to_value(&properties).unwrap();
+/// assert_eq!(json["$merchant_initiated_transaction"], true);
+/// assert_eq!(json["$merchant_profile"]["$merchant_id"], "12345");
+/// ```
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct TransactionProperties {
     /// Email of the user creating this transaction.
     ///
@@ -1877,8 +2694,8 @@ pub struct TransactionProperties {
     /// For transfer transactions, the user ID of the user receiving the transfer.
     ///
     /// If `transfer_recipient_user_id` is specified, `transaction_type` must be set to
-    /// `TransferType::Transfer`; otherwise, the system will give an error. Follow our [guidelines]
-    /// for `user_id` values.
+    /// [TransactionType::Transfer]; otherwise, [Event::validate] returns an error. Follow our
+    /// [guidelines] for `user_id` values.
     ///
     /// [guidelines]: https://sift.com/developers/docs/curl/events-api/fields
     #[serde(rename = "$transfer_recipient_user_id")]
@@ -1888,7 +2705,8 @@ pub struct TransactionProperties {
     ///
     /// Please note: Only send this field when `transaction_status` is
     /// `TransactionStatus::Failure`. Sending for transactions with `TransactionStatus::Success`,
-    /// `TransactionStatus::Pending` or `None` will result in error.
+    /// `TransactionStatus::Pending` or `None` will result in error, which [Event::validate]
+    /// catches before sending.
     ///
     /// This field trains the model on decline reasons across PSPs, helping Sift catch card testing
     /// and traditional payments fraud.
@@ -1901,6 +2719,12 @@ pub struct TransactionProperties {
     #[serde(rename = "$ordered_from")]
     pub ordered_from: Option<OrderedFrom>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to create the transaction.
     ///
     /// Represented by the [Browser] struct. Use this field if the client is a browser. Note: cannot
@@ -1945,7 +2769,7 @@ pub struct TransactionProperties {
     pub merchant_initiated_transaction: Option<bool>,
 
     /// The details about the merchant or seller providing the goods or service.
-    #[serde(rename = "$merchant_initiated_transaction")]
+    #[serde(rename = "$merchant_profile")]
     pub merchant_profile: Option<MerchantProfile>,
 
     /// The address to the specific physical location of the person sending a transaction.
@@ -1961,7 +2785,7 @@ pub struct TransactionProperties {
     /// A digital order represents a digital asset which can be part of a cryptocurrency or digital
     /// asset transaction.
     #[serde(rename = "$digital_orders")]
-    pub digital_orders: Vec<DigitalOrder>,
+    pub digital_orders: Option<Vec<DigitalOrder>>,
 
     /// The wallet ID or address of the person receiving a crypto payment.
     #[serde(rename = "$receiver_wallet_address")]
@@ -1972,7 +2796,7 @@ pub struct TransactionProperties {
     pub receiver_external_address: Option<bool>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -1980,7 +2804,7 @@ pub struct TransactionProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/update-account>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct UpdateAccountProperties {
     /// Track password changes via `Event::UpdatePassword`.
     ///
@@ -2037,6 +2861,12 @@ pub struct UpdateAccountProperties {
     #[serde(rename = "$social_sign_on_type")]
     pub social_sign_on_type: Option<SocialSignOn>,
 
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to create the account.
     ///
     /// Represented by the [Browser] object. Use this field if the client is a browser.
@@ -2080,7 +2910,7 @@ pub struct UpdateAccountProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -2088,8 +2918,14 @@ pub struct UpdateAccountProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/update-password>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct UpdatePasswordProperties {
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is used to update the password.
     ///
     /// Represented by the [Browser] object. Use this field if the client is a browser.
@@ -2123,7 +2959,7 @@ pub struct UpdatePasswordProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
 
@@ -2131,8 +2967,14 @@ pub struct UpdatePasswordProperties {
 ///
 /// <https://sift.com/developers/docs/curl/events-api/reserved-events/verification>
 #[skip_serializing_none]
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct VerificationProperties {
+    /// IP address of the request made by the user.
+    ///
+    /// Recommended for historical backfills and customers with mobile apps.
+    #[serde(rename = "$ip")]
+    pub ip: Option<String>,
+
     /// The user agent of the browser that is verifying.
     ///
     /// Represented by the [Browser] object. Use this field if the client is a browser.
@@ -2203,6 +3045,41 @@ pub struct VerificationProperties {
     pub site_domain: Option<String>,
 
     /// Any extra non-reserved fields to be recorded with the event.
-    #[serde(flatten)]
+    #[serde(flatten, deserialize_with = "deserialize_extra")]
     pub extra: Option<serde_json::Value>,
 }
+
+macro_rules! with_browser_ua {
+    ($($properties:ident),* $(,)?) => {
+        $(
+            impl $properties {
+                #[doc = concat!(
+                    "Sets [", stringify!($properties), "::browser] from the raw `User-Agent` ",
+                    "header.\n\nSee [Browser::from_user_agent].",
+                )]
+                #[must_use]
+                pub fn with_browser_ua(mut self, user_agent: impl Into<String>) -> Self {
+                    self.browser = Some(Browser::from_user_agent(user_agent));
+                    self
+                }
+            }
+        )*
+    };
+}
+
+with_browser_ua!(
+    AddItemToCartProperties,
+    AddPromotionProperties,
+    ContentStatusProperties,
+    CreateAccountProperties,
+    ContentProperties,
+    LoginProperties,
+    LogoutProperties,
+    OrderStatusProperties,
+    RemoveItemFromCartProperties,
+    SecurityNotificationProperties,
+    TransactionProperties,
+    UpdateAccountProperties,
+    UpdatePasswordProperties,
+    VerificationProperties,
+);
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// The status of the verification event.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum VerificationStatus {
     /// Your customer has attempted and passed the verification process.
@@ -19,7 +20,7 @@ pub enum VerificationStatus {
 }
 
 /// The type of the reserved event being verified
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum VerifiedEvent {
     /// The `Event::AddItemToCart` event.
@@ -83,8 +84,34 @@ pub enum VerifiedEvent {
     UpdatePassword,
 }
 
+impl VerifiedEvent {
+    /// Whether Sift needs a `$verified_entity_id` when verifying this event.
+    ///
+    /// Event to id mapping:
+    ///
+    /// * `login` -> Session ID
+    /// * `create_order` -> Order ID
+    /// * `create_content` -> Content ID
+    /// * `create_account`, `update_account`, or `update_password` -> No ID needed
+    ///
+    /// ```
+    /// use sift_science::events::VerifiedEvent;
+    ///
+    /// assert!(VerifiedEvent::Login.requires_entity_id());
+    /// assert!(VerifiedEvent::CreateOrder.requires_entity_id());
+    /// assert!(VerifiedEvent::CreateContent.requires_entity_id());
+    /// assert!(!VerifiedEvent::UpdatePassword.requires_entity_id());
+    /// ```
+    pub fn requires_entity_id(&self) -> bool {
+        matches!(
+            self,
+            VerifiedEvent::Login | VerifiedEvent::CreateOrder | VerifiedEvent::CreateContent
+        )
+    }
+}
+
 /// The type of verification being performed.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum VerificationType {
     /// An SMS is sent to the user's phone containing a code, URL or other process to authenticate
@@ -136,7 +163,7 @@ pub enum VerificationType {
 }
 
 /// The trigger for the verification.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum VerificationReason {
     /// The user opted to require a verification with every login.
@@ -155,7 +182,7 @@ pub enum VerificationReason {
 }
 
 /// The current state of the chargeback.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ChargebackState {
     /// Received
@@ -180,7 +207,7 @@ pub enum ChargebackState {
 }
 
 /// The reason given for a chargeback.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ChargebackReason {
     /// Fraud
@@ -205,7 +232,7 @@ pub enum ChargebackReason {
 }
 
 /// Captures the reason for the failure of a given login.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum LoginFailureReason {
     /// AccountUnknown username never existed on this site.
@@ -226,7 +253,7 @@ pub enum LoginFailureReason {
 }
 
 /// Supported social sign on types.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum SocialSignOn {
     /// Facebook
@@ -267,7 +294,24 @@ pub enum SocialSignOn {
 }
 
 /// The type of account a given user has.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Converts from a string, with values other than the known types kept as
+/// [AccountType::Other]:
+///
+/// ```
+/// use sift_science::events::AccountType;
+///
+/// assert_eq!(AccountType::from("merchant"), AccountType::Merchant);
+/// assert_eq!(
+///     AccountType::from("wholesale"),
+///     AccountType::Other("wholesale".into())
+/// );
+/// assert_eq!(
+///     serde_json::to_value(AccountType::from("wholesale")).unwrap(),
+///     "wholesale"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum AccountType {
@@ -282,10 +326,32 @@ pub enum AccountType {
 
     /// Premium
     Premium,
+
+    /// A custom account type.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for AccountType {
+    fn from(account_type: &str) -> Self {
+        match account_type {
+            "merchant" => AccountType::Merchant,
+            "shopper" => AccountType::Shopper,
+            "regular" => AccountType::Regular,
+            "premium" => AccountType::Premium,
+            other => AccountType::Other(other.into()),
+        }
+    }
+}
+
+impl From<String> for AccountType {
+    fn from(account_type: String) -> Self {
+        AccountType::from(account_type.as_str())
+    }
 }
 
 /// Represents the success or failure of a login attempt.
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum LoginStatus {
     /// Login success
@@ -298,7 +364,7 @@ pub enum LoginStatus {
 }
 
 /// The reason the password was updated or an update was attempted.
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum UpdatePasswordReason {
     /// The user updates the password on their own while logged into the account. The update can be
@@ -319,7 +385,7 @@ pub enum UpdatePasswordReason {
 }
 
 /// The status of the password update event.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum UpdatePasswordStatus {
     /// New password was set. This is the only status needed for password updates from within the
@@ -337,7 +403,7 @@ pub enum UpdatePasswordStatus {
 }
 
 /// Indicates the high-level state of the order.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum OrderStatus {
     /// An approved order.
@@ -362,7 +428,7 @@ pub enum OrderStatus {
 }
 
 /// The reason for a cancellation.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum OrderCancellationReason {
     /// Canceled for payment risk.
@@ -383,7 +449,7 @@ pub enum OrderCancellationReason {
 }
 
 /// The source of a decision.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum DecisionSource {
     /// Automated decision.
@@ -396,7 +462,7 @@ pub enum DecisionSource {
 }
 
 /// The type of notification issued.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum SecurityNotificationType {
     /// The notification was sent via email.
@@ -416,7 +482,7 @@ pub enum SecurityNotificationType {
 ///
 /// E.g. if you request payment method verification from a payment processor and receive a failure
 /// set the value to `PaymentMethodVerificationStatus::Failure`.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum PaymentMethodVerificationStatus {
     /// Successful verification
     #[serde(rename = "$success")]
@@ -432,7 +498,21 @@ pub enum PaymentMethodVerificationStatus {
 }
 
 /// The general type of payment being used.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Payment types not known to this version of the library round-trip as [PaymentType::Other]:
+///
+/// ```
+/// use sift_science::events::PaymentType;
+///
+/// let payment_type: PaymentType = serde_json::from_str(r#""$foo_pay""#).unwrap();
+/// assert_eq!(payment_type, PaymentType::Other("$foo_pay".into()));
+/// assert_eq!(serde_json::to_value(&payment_type).unwrap(), "$foo_pay");
+///
+/// let payment_type: PaymentType = serde_json::from_str(r#""$credit_card""#).unwrap();
+/// assert_eq!(payment_type, PaymentType::CreditCard);
+/// ```
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum PaymentType {
     /// Cash
     #[serde(rename = "$cash")]
@@ -455,6 +535,8 @@ pub enum PaymentType {
     DebitCard,
 
     /// Digital wallet
+    ///
+    /// Use `PaymentMethod::digital_wallet` to specify which wallet was used.
     #[serde(rename = "$digital_wallet")]
     DigitalWallet,
 
@@ -463,6 +545,9 @@ pub enum PaymentType {
     ElectronicFundTransfer,
 
     /// Financing
+    ///
+    /// Includes buy-now-pay-later providers, which should be specified as the
+    /// `PaymentMethod::payment_gateway` using [BnplProvider].
     #[serde(rename = "$financing")]
     Financing,
 
@@ -529,10 +614,118 @@ pub enum PaymentType {
     /// Wire debit
     #[serde(rename = "$wire_debit")]
     WireDebit,
+
+    /// A payment type not known to this version of the library, e.g. `"$foo_pay"`.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The digital wallet used for a [PaymentType::DigitalWallet] payment.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum DigitalWallet {
+    /// Apple Pay
+    #[serde(rename = "$apple_pay")]
+    ApplePay,
+
+    /// Google Pay
+    #[serde(rename = "$google_pay")]
+    GooglePay,
+
+    /// Samsung Pay
+    #[serde(rename = "$samsung_pay")]
+    SamsungPay,
+
+    /// Amazon Pay
+    #[serde(rename = "$amazon_pay")]
+    AmazonPay,
+
+    /// PayPal
+    #[serde(rename = "$paypal")]
+    PayPal,
+
+    /// Masterpass
+    #[serde(rename = "$masterpass")]
+    Masterpass,
+
+    /// Visa Checkout
+    #[serde(rename = "$visa_checkout")]
+    VisaCheckout,
+
+    /// Other digital wallet
+    #[serde(rename = "$other")]
+    Other,
+}
+
+/// Buy-now-pay-later providers for [PaymentType::Financing] payments.
+///
+/// Sift identifies these providers by their payment gateway, so convert them into the
+/// `PaymentMethod::payment_gateway` value:
+///
+/// ```
+/// use sift_science::events::{BnplProvider, PaymentMethod, PaymentType};
+///
+/// let payment_method = PaymentMethod {
+///     payment_type: Some(PaymentType::Financing),
+///     payment_gateway: Some(BnplProvider::Klarna.into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(payment_method.payment_gateway.as_deref(), Some("$klarna"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BnplProvider {
+    /// Affirm
+    Affirm,
+
+    /// Afterpay
+    Afterpay,
+
+    /// Klarna
+    Klarna,
+
+    /// Sezzle
+    Sezzle,
+
+    /// Zip
+    Zip,
+}
+
+impl fmt::Display for BnplProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BnplProvider::Affirm => write!(f, "$affirm"),
+            BnplProvider::Afterpay => write!(f, "$afterpay"),
+            BnplProvider::Klarna => write!(f, "$klarna"),
+            BnplProvider::Sezzle => write!(f, "$sezzle"),
+            BnplProvider::Zip => write!(f, "$zip"),
+        }
+    }
+}
+
+impl From<BnplProvider> for String {
+    fn from(provider: BnplProvider) -> Self {
+        provider.to_string()
+    }
 }
 
 /// The type of transaction being recorded.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Transaction types not known to this version of the library round-trip as
+/// [TransactionType::Other]:
+///
+/// ```
+/// use sift_science::events::TransactionType;
+///
+/// let transaction_type: TransactionType = serde_json::from_str(r#""$stake""#).unwrap();
+/// assert_eq!(transaction_type, TransactionType::Other("$stake".into()));
+/// assert_eq!(serde_json::to_value(&transaction_type).unwrap(), "$stake");
+///
+/// let transaction_type: TransactionType = serde_json::from_str(r#""$sale""#).unwrap();
+/// assert_eq!(transaction_type, TransactionType::Sale);
+/// ```
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum TransactionType {
     /// Authorization and capture of a payment performed together in one step.
@@ -593,10 +786,14 @@ pub enum TransactionType {
     /// For example, receiving funds through remittance services.
     #[serde(rename = "$receive")]
     Receive,
+
+    /// A transaction type not known to this version of the library.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Indicates the status of the transaction.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum TransactionStatus {
     /// A successful transaction
@@ -613,7 +810,21 @@ pub enum TransactionStatus {
 }
 
 /// Indicates the category of a transaction decline sent by a PSP.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Categories not known to this version of the library round-trip as
+/// [DeclineCategory::Unknown], while Sift's own `$other` category is [DeclineCategory::Other]:
+///
+/// ```
+/// use sift_science::events::DeclineCategory;
+///
+/// let category: DeclineCategory = serde_json::from_str(r#""$velocity""#).unwrap();
+/// assert_eq!(category, DeclineCategory::Unknown("$velocity".into()));
+/// assert_eq!(serde_json::to_value(&category).unwrap(), "$velocity");
+///
+/// let category: DeclineCategory = serde_json::from_str(r#""$other""#).unwrap();
+/// assert_eq!(category, DeclineCategory::Other);
+/// ```
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum DeclineCategory {
     /// Decliened for fraud.
@@ -659,10 +870,82 @@ pub enum DeclineCategory {
     /// Other decline category
     #[serde(rename = "$other")]
     Other,
+
+    /// A decline category not known to this version of the library.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Payment processors whose decline reason codes can be interpreted by Sift.
+///
+/// Converts into the reserved `PaymentMethod::payment_gateway` value for the processor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PaymentProcessor {
+    /// Adyen
+    Adyen,
+
+    /// Braintree
+    Braintree,
+
+    /// Checkout.com
+    CheckoutCom,
+
+    /// Cybersource
+    Cybersource,
+
+    /// Stripe
+    Stripe,
+
+    /// Worldpay
+    Worldpay,
+}
+
+impl fmt::Display for PaymentProcessor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaymentProcessor::Adyen => write!(f, "$adyen"),
+            PaymentProcessor::Braintree => write!(f, "$braintree"),
+            PaymentProcessor::CheckoutCom => write!(f, "$checkoutcom"),
+            PaymentProcessor::Cybersource => write!(f, "$cybersource"),
+            PaymentProcessor::Stripe => write!(f, "$stripe"),
+            PaymentProcessor::Worldpay => write!(f, "$worldpay"),
+        }
+    }
+}
+
+impl From<PaymentProcessor> for String {
+    fn from(processor: PaymentProcessor) -> Self {
+        processor.to_string()
+    }
+}
+
+/// A decline reason code together with the processor that issued it.
+///
+/// Decline codes are processor specific (e.g. Stripe's `card_declined` vs Adyen's numeric refusal
+/// reasons), so Sift needs to know the processor to interpret the code. Attach to a payment method
+/// with `PaymentMethod::with_decline_reason`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeclineReason {
+    /// The processor that declined the payment.
+    pub processor: PaymentProcessor,
+
+    /// The raw decline code returned by the processor.
+    pub code: String,
+}
+
+impl DeclineReason {
+    /// Create a new decline reason for a processor's decline code.
+    pub fn new(processor: PaymentProcessor, code: impl Into<String>) -> Self {
+        DeclineReason {
+            processor,
+            code: code.into(),
+        }
+    }
 }
 
 /// Indicates the status of a 3DS request.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum Status3Ds {
     /// Successful
@@ -687,7 +970,7 @@ pub enum Status3Ds {
 }
 
 /// Reflects the source of an initiated challenge.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum Triggered3Ds {
     /// Used to reflect a challenge initiated by the processor.
@@ -701,7 +984,7 @@ pub enum Triggered3Ds {
 }
 
 /// Indicates the method of delivery to the user.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ShippingMethod {
     /// Electronic shipping
@@ -714,7 +997,7 @@ pub enum ShippingMethod {
 }
 
 /// The status of a posting.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ContentStatus {
     /// The posting has not yet been submitted by the user to go live.
@@ -748,7 +1031,7 @@ pub enum ContentStatus {
 }
 
 /// The reason provided by the flagger.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ContentFlagReason {
     /// Foul language, harassment, hate speech or bullying.
@@ -11,13 +11,46 @@
 //! For customers already using Labels API, don't worry! It is still a supported integration
 //! method. If you are interested in migrating to Decisions, please contact your account manager or
 //! support@sift.com and we can help.
+//!
+//! The labels applied to an entity are returned in a score response as [LatestLabels], with a
+//! [Label] per abuse type. These are the same types as [events::LatestLabels] and [events::Label],
+//! re-exported here so labels API users find them alongside [LabelProperties].
+//!
+//! ```
+//! use sift_science::{events, labels, AbuseType};
+//!
+//! let fixture = serde_json::json!({
+//!     "account_abuse": { "is_bad": true, "time": 1350201660000u64 }
+//! });
+//!
+//! let from_labels: labels::LatestLabels = serde_json::from_value(fixture.clone()).unwrap();
+//! let from_events: events::LatestLabels = serde_json::from_value(fixture).unwrap();
+//!
+//! // One type, reachable from both modules
+//! let same: &events::Label = from_labels.get(AbuseType::AccountAbuse).unwrap();
+//! let also_same: &labels::Label = from_events.get(AbuseType::AccountAbuse).unwrap();
+//! assert_eq!(same.is_bad(), also_same.is_bad());
+//!
+//! let response: events::ScoreResponse = serde_json::from_value(serde_json::json!({
+//!     "status": 0,
+//!     "error_message": "OK",
+//!     "latest_labels": { "account_abuse": { "is_bad": true, "time": 1350201660000u64 } }
+//! }))
+//! .unwrap();
+//! let latest_labels: Option<labels::LatestLabels> = response.latest_labels;
+//! assert!(latest_labels.unwrap().get(AbuseType::AccountAbuse).unwrap().is_bad());
+//! ```
 
+#[cfg(feature = "decisions")]
+use crate::decisions::DecisionRequest;
 use crate::{
     events::{self, ApiVersion, Event, EventOptions},
     AbuseType,
 };
 use std::time::Duration;
 
+pub use crate::events::{Label, LatestLabels};
+
 /// Details of the label
 #[derive(Debug)]
 pub struct LabelProperties {
@@ -78,7 +111,7 @@ impl From<LabelProperties> for Event {
 }
 
 /// Optional parameters for the label request
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct LabelOptions {
     /// Overrides the timeout for this call.
     pub timeout: Option<Duration>,
@@ -88,6 +121,30 @@ pub struct LabelOptions {
 
     /// Overrides the version of the Events API to call.
     pub version: Option<ApiVersion>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
+}
+
+/// Optional parameters for [Client::label_many].
+///
+/// [Client::label_many]: crate::Client::label_many
+#[derive(Clone, Debug)]
+pub struct LabelManyOptions {
+    /// The most labels sent at once.
+    pub concurrency: usize,
+
+    /// Options used for each label.
+    pub label_options: LabelOptions,
+}
+
+impl Default for LabelManyOptions {
+    fn default() -> Self {
+        LabelManyOptions {
+            concurrency: 8,
+            label_options: LabelOptions::default(),
+        }
+    }
 }
 
 impl From<(LabelOptions, &str)> for EventOptions {
@@ -96,14 +153,96 @@ impl From<(LabelOptions, &str)> for EventOptions {
             api_key,
             timeout,
             version,
+            extra_headers,
         } = opts;
 
         EventOptions {
             api_key,
             timeout,
             version,
+            extra_headers,
             path: Some(format!("users/{}/labels", user_id).into()),
             ..Default::default()
         }
     }
 }
+
+/// Where a label sent with [Client::label_or_decide] is routed.
+///
+/// Useful when migrating from the Labels API to Decisions, so call sites don't need to be rewritten
+/// and the switch can be made by flipping a flag.
+///
+/// [Client::label_or_decide]: crate::Client::label_or_decide
+#[cfg(feature = "decisions")]
+#[derive(Copy, Clone, Debug)]
+pub enum LabelRoute {
+    /// Send the label to the legacy Labels API.
+    Label,
+
+    /// Apply a decision to the user instead of labeling them.
+    ///
+    /// The function maps `(is_fraud, abuse_type)` to the id of a decision configured in the
+    /// [Decisions section] of the console.
+    ///
+    /// [Decisions section]: https://sift.com/console/decisions
+    Decision(fn(bool, AbuseType) -> String),
+}
+
+#[cfg(feature = "decisions")]
+impl LabelRoute {
+    /// Route to the Decisions API when `use_decisions` is set, using the
+    /// [recommended decision ids](LabelRoute::recommended_decision_id), or to the Labels API
+    /// otherwise.
+    pub fn from_flag(use_decisions: bool) -> Self {
+        if use_decisions {
+            LabelRoute::Decision(LabelRoute::recommended_decision_id)
+        } else {
+            LabelRoute::Label
+        }
+    }
+
+    /// The recommended mapping from a label to a decision id.
+    ///
+    /// Fraudulent users map to `block_user_{abuse_type}` and legitimate users map to
+    /// `looks_ok_{abuse_type}`, e.g. `block_user_payment_abuse` or `looks_ok_account_takeover`.
+    /// Create decisions with matching ids for each abuse type you label, or supply your own mapping
+    /// with [LabelRoute::Decision].
+    pub fn recommended_decision_id(is_fraud: bool, abuse_type: AbuseType) -> String {
+        if is_fraud {
+            format!("block_user_{}", abuse_type)
+        } else {
+            format!("looks_ok_{}", abuse_type)
+        }
+    }
+}
+
+#[cfg(feature = "decisions")]
+impl LabelProperties {
+    /// Convert the label into a decision request using the given decision id mapping.
+    ///
+    /// Labels with an `analyst` are treated as [Source::ManualReview](crate::decisions::Source::ManualReview), all others as
+    /// [Source::AutomatedRule](crate::decisions::Source::AutomatedRule).
+    pub(crate) fn into_decision(
+        self,
+        decision_id: fn(bool, AbuseType) -> String,
+    ) -> DecisionRequest {
+        let LabelProperties {
+            is_fraud,
+            abuse_type,
+            description,
+            analyst,
+            ..
+        } = self;
+
+        let decision_id = decision_id(is_fraud, abuse_type);
+        let decision = match analyst {
+            Some(analyst) => DecisionRequest::manual_review(decision_id, analyst),
+            None => DecisionRequest::automated(decision_id),
+        };
+
+        DecisionRequest {
+            description,
+            ..decision
+        }
+    }
+}
@@ -9,19 +9,49 @@ mod error;
 pub mod events;
 #[cfg(feature = "labels")]
 pub mod labels;
+mod metrics;
+#[cfg(feature = "test-util")]
+mod mock;
+mod pagination;
+mod retry;
 #[cfg(feature = "score")]
 pub mod score;
+#[cfg(feature = "sink")]
+mod sink;
 #[cfg(feature = "verification")]
 pub mod verification;
 #[cfg(feature = "webhooks")]
 pub mod webhooks;
+#[cfg(feature = "workflows")]
+pub mod workflows;
 
 #[cfg(feature = "awc3")]
 pub use client::Awc3Client;
 #[cfg(feature = "awc")]
 pub use client::AwcClient;
+#[cfg(feature = "hyper")]
+pub use client::HyperClient;
+pub use client::QueryParams;
 #[cfg(feature = "reqwest")]
 pub use client::ReqwestClient;
-pub use client::{Client, HttpClient};
+#[cfg(feature = "reqwest-middleware")]
+pub use client::ReqwestMiddlewareClient;
+#[cfg(feature = "surf")]
+pub use client::SurfClient;
+#[cfg(feature = "ureq")]
+pub use client::UreqClient;
+pub use client::{Client, ClientBuilder, HttpClient, ResponseMeta, SendHttpClient, Timeouts};
+#[cfg(feature = "chrono")]
+pub use common::parse_rfc3339;
 pub use common::AbuseType;
-pub use error::{Error, Result};
+pub use error::{Error, Result, SiftErrorCode};
+pub use metrics::{Metrics, RequestEnd};
+#[cfg(feature = "test-util")]
+pub use mock::{
+    Method, MockHttpClient, MockResponse, MockServer, ReceivedRequest, RecordedRequest,
+};
+pub use pagination::{Page, Paginator};
+pub use retry::RetryPolicy;
+pub use rust_decimal::Decimal;
+#[cfg(feature = "sink")]
+pub use sink::{EventSink, SinkOptions};
@@ -0,0 +1,47 @@
+use crate::Error;
+use std::time::Duration;
+
+/// A hook for recording request metrics, e.g. latency histograms and success or error counters.
+///
+/// Each [Client] method reports the start and end of its API call, tagged with an endpoint name
+/// such as `"track"` or `"get_user_score"`. Retries are included in the reported duration. Both
+/// callbacks default to doing nothing.
+///
+/// ```
+/// use sift_science::{Metrics, RequestEnd};
+///
+/// struct LogLatency;
+///
+/// impl Metrics for LogLatency {
+///     fn on_request_end(&self, end: RequestEnd<'_>) {
+///         println!("{} took {:?}", end.endpoint, end.duration);
+///     }
+/// }
+/// ```
+///
+/// [Client]: crate::Client
+pub trait Metrics: Send + Sync {
+    /// Called before an API call is made.
+    fn on_request_start(&self, endpoint: &'static str) {
+        let _ = endpoint;
+    }
+
+    /// Called once an API call has finished, successfully or not.
+    fn on_request_end(&self, end: RequestEnd<'_>) {
+        let _ = end;
+    }
+}
+
+/// A finished API call, reported to [Metrics::on_request_end].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct RequestEnd<'a> {
+    /// The endpoint name, e.g. `"track"` or `"get_user_score"`.
+    pub endpoint: &'static str,
+
+    /// Whether the call succeeded, or the error it failed with.
+    pub status: std::result::Result<(), &'a Error>,
+
+    /// How long the call took, including any retries.
+    pub duration: Duration,
+}
@@ -0,0 +1,533 @@
+//! A mock [HttpClient] for testing code that talks to Sift, and a mock server for testing the
+//! built-in HTTP clients.
+
+use crate::{client::QueryParams, Error, ResponseMeta, Result, SendHttpClient};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::task::{Context, Poll};
+use std::thread;
+use std::time::Duration;
+
+/// The HTTP method of a [RecordedRequest].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// A GET request
+    Get,
+
+    /// A POST request
+    Post,
+
+    /// A PUT request
+    Put,
+
+    /// A DELETE request
+    Delete,
+}
+
+// A canned response, or the error to fail with
+type Response = Result<Option<serde_json::Value>>;
+
+/// A request captured by [MockHttpClient].
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    /// The request method.
+    pub method: Method,
+
+    /// The full request url, without query params.
+    pub url: String,
+
+    /// The serialized query params, if any were sent.
+    pub query: Option<String>,
+
+    /// The JSON request body, if any was sent.
+    pub body: Option<serde_json::Value>,
+
+    /// The request timeout.
+    pub timeout: Duration,
+
+    /// The basic auth username, if any was sent.
+    pub username: Option<String>,
+
+    /// Extra headers sent with the request.
+    pub headers: Vec<(String, String)>,
+}
+
+/// An [HttpClient] that records requests and replies with canned responses.
+///
+/// Only available with the `test-util` feature.
+///
+/// Responses are queued per [Method] and returned in order. When no response is queued, `POST`
+/// and `DELETE` requests succeed without a body, while `GET` and `PUT` requests fail with an
+/// [Error::Server].
+///
+/// The mock claims [gzip support], so request compression can be tested; bodies are always recorded
+/// uncompressed, with the `Content-Encoding` header in [RecordedRequest::headers].
+///
+/// Clones share their recorded requests and queued responses, like clones of a pooled HTTP client
+/// share connections.
+///
+/// Each request yields to the executor once before it completes, so concurrent requests overlap
+/// as they would over the network, see [MockHttpClient::max_concurrent_requests].
+///
+/// [HttpClient]: crate::HttpClient
+/// [gzip support]: crate::HttpClient::supports_gzip
+///
+/// ```
+/// use futures::executor::block_on;
+/// use sift_science::{
+///     events::{Event, EventOptions, LoginProperties},
+///     Client, MockHttpClient,
+/// };
+///
+/// let sift = Client::new("api_key", MockHttpClient::new());
+///
+/// block_on(sift.track(
+///     Event::Login {
+///         user_id: "billy_jones_301".into(),
+///         session_id: None,
+///         properties: LoginProperties::default(),
+///     },
+///     EventOptions::default(),
+/// ))
+/// .unwrap();
+///
+/// sift.http_client.assert_posted_to("/v205/events");
+/// assert_eq!(sift.http_client.bodies()[0]["$user_id"], "billy_jones_301");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MockHttpClient {
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    responses: Arc<Mutex<HashMap<Method, VecDeque<Response>>>>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl MockHttpClient {
+    /// Create a new mock client with no queued responses.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Queue a JSON response for the next request with the given method.
+    pub fn respond_with(&self, method: Method, response: serde_json::Value) -> &Self {
+        self.enqueue(method, Ok(Some(response)))
+    }
+
+    /// Queue an empty response, e.g. a `204 No Content`, for the next request with the given
+    /// method.
+    pub fn respond_with_empty(&self, method: Method) -> &Self {
+        self.enqueue(method, Ok(None))
+    }
+
+    /// Queue an error for the next request with the given method.
+    pub fn fail_with(&self, method: Method, error: Error) -> &Self {
+        self.enqueue(method, Err(error))
+    }
+
+    /// All requests made so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The JSON bodies of all requests made so far, in order.
+    pub fn bodies(&self) -> Vec<serde_json::Value> {
+        self.requests()
+            .into_iter()
+            .filter_map(|req| req.body)
+            .collect()
+    }
+
+    /// The most requests that were in flight at once.
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Assert that a request with the given method was made to a url ending in `path`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no matching request was made.
+    pub fn assert_requested(&self, method: Method, path: &str) {
+        let requests = self.requests();
+        assert!(
+            requests
+                .iter()
+                .any(|req| req.method == method && req.url.ends_with(path)),
+            "expected a {:?} request to {}, got {:?}",
+            method,
+            path,
+            requests
+                .iter()
+                .map(|req| (req.method, req.url.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    /// Assert that a `POST` request was made to a url ending in `path`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no matching request was made.
+    pub fn assert_posted_to(&self, path: &str) {
+        self.assert_requested(Method::Post, path)
+    }
+
+    fn enqueue(&self, method: Method, response: Response) -> &Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(method)
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    async fn record(&self, request: RecordedRequest) -> Option<Response> {
+        let method = request.method;
+        self.requests.lock().unwrap().push(request);
+
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .get_mut(&method)
+            .and_then(VecDeque::pop_front);
+
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        YieldNow(false).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        response
+    }
+}
+
+// Returns pending once, so other futures run before the request completes
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+impl SendHttpClient for MockHttpClient {
+    async fn get(
+        &self,
+        url: &str,
+        query_params: &QueryParams,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        match self
+            .record(RecordedRequest {
+                method: Method::Get,
+                url: url.into(),
+                query: serde_urlencoded::to_string(query_params).ok(),
+                body: None,
+                timeout,
+                username: username.map(Into::into),
+                headers: headers.to_vec(),
+            })
+            .await
+        {
+            Some(response) => {
+                response?.ok_or_else(|| Error::Server("mock GET response has no body".into()))
+            }
+            None => Err(Error::Server(format!("no mock GET response for {}", url))),
+        }
+    }
+
+    async fn post(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        body: Option<&serde_json::Value>,
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<Option<serde_json::Value>> {
+        self.record(RecordedRequest {
+            method: Method::Post,
+            url: url.into(),
+            query: query_params
+                .and_then(|query_params| serde_urlencoded::to_string(query_params).ok()),
+            body: body.cloned(),
+            timeout,
+            username: username.map(Into::into),
+            headers: headers.to_vec(),
+        })
+        .await
+        .unwrap_or(Ok(None))
+    }
+
+    async fn post_gzip(
+        &self,
+        url: &str,
+        query_params: Option<&QueryParams>,
+        json: &[u8],
+        timeout: Duration,
+        username: Option<&str>,
+        headers: &[(String, String)],
+    ) -> Result<(Option<serde_json::Value>, ResponseMeta)> {
+        let mut headers = headers.to_vec();
+        headers.push(("Content-Encoding".into(), "gzip".into()));
+
+        let body = self
+            .record(RecordedRequest {
+                method: Method::Post,
+                url: url.into(),
+                query: query_params
+                    .and_then(|query_params| serde_urlencoded::to_string(query_params).ok()),
+                body: Some(serde_json::from_slice(json)?),
+                timeout,
+                username: username.map(Into::into),
+                headers,
+            })
+            .await
+            .unwrap_or(Ok(None))?;
+
+        Ok((body, ResponseMeta::default()))
+    }
+
+    fn supports_gzip(&self) -> bool {
+        true
+    }
+
+    async fn put(
+        &self,
+        url: &str,
+        body: &serde_json::Value,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<serde_json::Value> {
+        match self
+            .record(RecordedRequest {
+                method: Method::Put,
+                url: url.into(),
+                query: None,
+                body: Some(body.clone()),
+                timeout,
+                username: Some(username.into()),
+                headers: headers.to_vec(),
+            })
+            .await
+        {
+            Some(response) => {
+                response?.ok_or_else(|| Error::Server("mock PUT response has no body".into()))
+            }
+            None => Err(Error::Server(format!("no mock PUT response for {}", url))),
+        }
+    }
+
+    async fn delete(
+        &self,
+        url: &str,
+        timeout: Duration,
+        username: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
+        self.record(RecordedRequest {
+            method: Method::Delete,
+            url: url.into(),
+            query: None,
+            body: None,
+            timeout,
+            username: Some(username.into()),
+            headers: headers.to_vec(),
+        })
+        .await
+        .unwrap_or(Ok(None))
+        .map(|_| ())
+    }
+}
+
+/// A canned response sent by a [MockServer].
+#[derive(Clone, Debug)]
+pub struct MockResponse(String);
+
+impl MockResponse {
+    /// A JSON response with the given status, or an empty one if `body` is empty.
+    pub fn json(status: u16, body: &str) -> Self {
+        MockResponse(format!(
+            "HTTP/1.1 {} Status\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        ))
+    }
+
+    /// A raw HTTP response, sent as is, for custom headers or non-JSON bodies.
+    pub fn raw(response: impl Into<String>) -> Self {
+        MockResponse(response.into())
+    }
+}
+
+/// A request received by a [MockServer].
+#[derive(Clone, Debug)]
+pub struct ReceivedRequest {
+    /// The request line, e.g. `POST /v205/events HTTP/1.1`.
+    pub request_line: String,
+
+    /// The request headers, with lowercase names.
+    pub headers: Vec<(String, String)>,
+
+    /// The raw request body.
+    pub body: Vec<u8>,
+}
+
+impl ReceivedRequest {
+    /// The value of the header with the given name, which is matched case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A local HTTP server replying to requests with canned responses, for testing the built-in HTTP
+/// clients end to end.
+///
+/// Only available with the `test-util` feature.
+///
+/// Each request is read from a new connection and answered with the next queued response, or a
+/// `204 No Content` once they run out. Point a [Client] at the server with [Client::with_origin].
+///
+/// [Client]: crate::Client
+/// [Client::with_origin]: crate::Client::with_origin
+///
+/// ```
+/// # #[cfg(feature = "ureq")]
+/// # {
+/// use futures::executor::block_on;
+/// use sift_science::{
+///     events::{Event, EventOptions, LoginProperties},
+///     Client, MockResponse, MockServer,
+/// };
+///
+/// let server = MockServer::start(vec![MockResponse::json(
+///     200,
+///     r#"{"status": 0, "error_message": "OK"}"#,
+/// )]);
+/// let sift = Client::new("api_key", ureq::Agent::new()).with_origin(server.origin());
+///
+/// let login = Event::Login {
+///     user_id: "billy_jones_301".into(),
+///     session_id: None,
+///     properties: LoginProperties::default(),
+/// };
+/// block_on(sift.track(login, EventOptions::default())).unwrap();
+///
+/// assert_eq!(server.next_request().request_line, "POST /v205/events HTTP/1.1");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockServer {
+    origin: String,
+    requests: Mutex<mpsc::Receiver<ReceivedRequest>>,
+}
+
+impl MockServer {
+    /// Start a server on a free local port, replying with `responses` in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server can't bind a local port.
+    pub fn start(responses: Vec<MockResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind mock server");
+        let origin = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let mut stream = BufReader::new(stream);
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+
+                let response = responses
+                    .next()
+                    .map(|response| response.0)
+                    .unwrap_or_else(|| {
+                        "HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n".into()
+                    });
+                let _ = stream.get_mut().write_all(response.as_bytes());
+                let _ = tx.send(request);
+            }
+        });
+
+        MockServer {
+            origin,
+            requests: Mutex::new(rx),
+        }
+    }
+
+    /// The origin to send requests to, e.g. `http://127.0.0.1:1234`.
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    /// The next request received, waiting up to 5 seconds for it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no request is received in time.
+    pub fn next_request(&self) -> ReceivedRequest {
+        self.requests
+            .lock()
+            .unwrap()
+            .recv_timeout(Duration::from_secs(5))
+            .expect("no request received by the mock server")
+    }
+}
+
+// Read a request line, its headers and a body of `Content-Length` bytes
+fn read_request(stream: &mut impl BufRead) -> Option<ReceivedRequest> {
+    let mut request_line = String::new();
+    stream.read_line(&mut request_line).ok()?;
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).ok()?;
+        match line.trim_end().split_once(':') {
+            Some((name, value)) => {
+                headers.push((name.to_ascii_lowercase(), value.trim().to_string()))
+            }
+            None => break,
+        }
+    }
+
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; content_length];
+    stream.read_exact(&mut body).ok()?;
+
+    Some(ReceivedRequest {
+        request_line: request_line.trim_end().to_string(),
+        headers,
+        body,
+    })
+}
@@ -0,0 +1,98 @@
+use crate::Result;
+use futures::{
+    future::Future,
+    stream::{self, Stream, TryStreamExt},
+};
+
+/// A page of results from a list endpoint that pages with a `from` offset.
+pub trait Page {
+    /// The type of the results.
+    type Item;
+
+    /// Whether there are more results after this page.
+    fn has_more(&self) -> bool;
+
+    /// The results in this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Streams the results of a list endpoint, fetching pages as they are needed.
+///
+/// `fetch` is called with the `from` offset of each page, starting at the given offset, and
+/// the next page starts after the results of the last. Pages are fetched until one has
+/// `has_more` unset or no results, the next offset would overflow a `u32`, or a fetch fails, in
+/// which case the error is the last item.
+///
+/// ```
+/// use futures::{executor::block_on, TryStreamExt};
+/// use sift_science::{Page, Paginator, Result};
+///
+/// struct Numbers {
+///     numbers: Vec<u32>,
+///     has_more: bool,
+/// }
+///
+/// impl Page for Numbers {
+///     type Item = u32;
+///
+///     fn has_more(&self) -> bool {
+///         self.has_more
+///     }
+///
+///     fn into_items(self) -> Vec<u32> {
+///         self.numbers
+///     }
+/// }
+///
+/// let numbers = Paginator::new(0, |from| async move {
+///     Result::Ok(Numbers {
+///         numbers: (from..(from + 2).min(5)).collect(),
+///         has_more: from + 2 < 5,
+///     })
+/// })
+/// .into_stream();
+///
+/// let numbers: Vec<u32> = block_on(numbers.try_collect()).unwrap();
+/// assert_eq!(numbers, vec![0, 1, 2, 3, 4]);
+/// ```
+#[derive(Debug)]
+pub struct Paginator<F> {
+    from: u32,
+    fetch: F,
+}
+
+impl<F, Fut, P> Paginator<F>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<P>>,
+    P: Page,
+{
+    /// Page through results starting at the `from` offset.
+    pub fn new(from: u32, fetch: F) -> Self {
+        Paginator { from, fetch }
+    }
+
+    /// The results of every page, in order.
+    pub fn into_stream(self) -> impl Stream<Item = Result<P::Item>> {
+        let Paginator { from, fetch } = self;
+
+        stream::unfold((fetch, Some(from)), |(mut fetch, from)| async move {
+            let from = from?;
+
+            match fetch(from).await {
+                Ok(page) => {
+                    let has_more = page.has_more();
+                    let items = page.into_items();
+                    let next = (has_more && !items.is_empty())
+                        .then(|| u32::try_from(items.len()).ok()?.checked_add(from))
+                        .flatten();
+
+                    Some((Ok(items), (fetch, next)))
+                }
+                Err(err) => Some((Err(err), (fetch, None))),
+            }
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
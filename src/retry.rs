@@ -0,0 +1,204 @@
+use crate::Error;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+type SleepFuture = futures::future::BoxFuture<'static, ()>;
+
+type SleepFn = dyn Fn(Duration) -> SleepFuture + Send + Sync;
+
+/// Automatic retry configuration for a [Client].
+///
+/// Requests that fail with a transport error, a timeout, an HTTP `5xx` response, a Sift server
+/// error (negative status) or rate limiting are retried with exponential backoff; see
+/// [Error::is_retryable]. Client errors, like an invalid API key, a malformed event or a `4xx`
+/// response, are never retried.
+///
+/// Only requests that are safe to repeat are retried by default: scores and rescoring, decision
+/// status and listing, workflow runs, removing labels, and reading, updating or deleting webhooks.
+/// A failed attempt may still have reached Sift, e.g. after a timeout, so requests that would
+/// record something twice are not: tracking events is only retried when opted in with
+/// [EventOptions::retry], while labeling users, sending or checking verifications, applying
+/// decisions, and creating webhooks are never retried.
+///
+/// ```
+/// use sift_science::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy {
+///     max_retries: 5,
+///     base_delay: Duration::from_millis(100),
+///     max_delay: Duration::from_secs(1),
+///     jitter: false,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(policy.delay(0), Duration::from_millis(100));
+/// assert_eq!(policy.delay(2), Duration::from_millis(400));
+/// assert_eq!(policy.delay(4), Duration::from_secs(1));
+/// ```
+///
+/// [Client]: crate::Client
+/// [EventOptions::retry]: crate::events::EventOptions::retry
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+
+    /// The delay before the first retry, doubled for each subsequent retry.
+    pub base_delay: Duration,
+
+    /// The upper bound for the delay between retries.
+    pub max_delay: Duration,
+
+    /// Randomize each delay between half and all of its computed value.
+    ///
+    /// Spreads out retries from many clients failing at the same time.
+    pub jitter: bool,
+
+    /// How to wait between retries.
+    pub sleep: Arc<SleepFn>,
+}
+
+impl RetryPolicy {
+    /// Override how the client waits between retries.
+    ///
+    /// By default the client sleeps with `tokio::time::sleep` when it is built with the `hyper`,
+    /// `reqwest` or `sink` features and is running on a tokio runtime, and with
+    /// `async_std::task::sleep` when built with the `surf` feature. Otherwise each retry spawns a
+    /// thread that sleeps for the delay, so that retries work with any async runtime.
+    /// Use this to plug in your runtime's timer instead, or a fake clock in tests.
+    ///
+    /// The future must be `Send`, so that [Client] futures can be.
+    ///
+    /// ```
+    /// use sift_science::RetryPolicy;
+    ///
+    /// let policy = RetryPolicy::default().with_sleep(|delay| Box::pin(tokio::time::sleep(delay)));
+    /// ```
+    ///
+    /// [Client]: crate::Client
+    pub fn with_sleep<F>(mut self, sleep: F) -> Self
+    where
+        F: Fn(Duration) -> SleepFuture + Send + Sync + 'static,
+    {
+        self.sleep = Arc::new(sleep);
+        self
+    }
+
+    /// The delay before the given retry, starting at 0 for the first retry.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .checked_mul(2u32.saturating_pow(retry))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        if self.jitter {
+            let random = RandomState::new().build_hasher().finish();
+            delay / 2 + delay.mul_f64((random % 1000) as f64 / 2000.0)
+        } else {
+            delay
+        }
+    }
+
+    /// The delay before retrying a request that failed with the given error.
+    ///
+    /// Waits as long as Sift asked for in an [Error::RateLimited], up to `max_delay`, and
+    /// [RetryPolicy::delay] otherwise.
+    ///
+    /// ```
+    /// use sift_science::{Error, RetryPolicy};
+    /// use std::time::Duration;
+    ///
+    /// let policy = RetryPolicy {
+    ///     base_delay: Duration::from_millis(100),
+    ///     max_delay: Duration::from_secs(10),
+    ///     jitter: false,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let rate_limited = |retry_after| Error::RateLimited { retry_after };
+    /// assert_eq!(
+    ///     policy.retry_delay(0, &rate_limited(Some(Duration::from_secs(5)))),
+    ///     Duration::from_secs(5)
+    /// );
+    /// assert_eq!(
+    ///     policy.retry_delay(1, &rate_limited(None)),
+    ///     Duration::from_millis(200)
+    /// );
+    ///
+    /// // Long delays are capped
+    /// assert_eq!(
+    ///     policy.retry_delay(0, &rate_limited(Some(Duration::from_secs(3600)))),
+    ///     Duration::from_secs(10)
+    /// );
+    /// ```
+    pub fn retry_delay(&self, retry: u32, err: &Error) -> Duration {
+        match err {
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } => (*retry_after).min(self.max_delay),
+            _ => self.delay(retry),
+        }
+    }
+
+    /// Whether a request that failed with the given error should be retried.
+    pub fn is_retryable(&self, err: &Error) -> bool {
+        err.is_retryable()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(2),
+            jitter: true,
+            sleep: Arc::new(default_sleep),
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish()
+    }
+}
+
+// Sleep on the timer of the runtime the HTTP client runs on, falling back to a timer thread when
+// built without one
+fn default_sleep(delay: Duration) -> SleepFuture {
+    #[cfg(any(feature = "hyper", feature = "reqwest", feature = "sink"))]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Box::pin(tokio::time::sleep(delay));
+    }
+
+    #[cfg(feature = "surf")]
+    return Box::pin(async_std::task::sleep(delay));
+
+    #[cfg(not(feature = "surf"))]
+    thread_sleep(delay)
+}
+
+// Runtime agnostic sleep, waking the waiting future from a timer thread
+#[cfg(not(feature = "surf"))]
+fn thread_sleep(delay: Duration) -> SleepFuture {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        let _ = tx.send(());
+    });
+
+    Box::pin(async move {
+        let _ = rx.await;
+    })
+}
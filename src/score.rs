@@ -68,10 +68,22 @@ pub struct ScoreOptions {
     pub version: Option<ApiVersion>,
 
     /// Overrides the URI path prefix for this API call.
+    ///
+    /// Prefer [Client::get_session_score](crate::Client::get_session_score) and
+    /// [Client::rescore_session](crate::Client::rescore_session) for scoring sessions.
     pub path_prefix: Option<&'static str>,
 
     /// Overrides the URI path suffix for this API call.
     pub path_suffix: Option<&'static str>,
+
+    /// If true, requests that the response include the status of any workflow run for the user.
+    pub return_workflow_status: Option<bool>,
+
+    /// If true, requests that workflow statuses include the route taken through each workflow.
+    pub return_route_info: Option<bool>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
 }
 
 /// Query params for the score api.
@@ -87,6 +99,12 @@ pub(crate) struct ScoreQueryParams {
     /// By default, a score is returned for every abuse type to which you are subscribed.
     #[serde(serialize_with = "abuse_type_serialize")]
     pub(crate) abuse_types: Option<Vec<AbuseType>>,
+
+    /// If true, requests that the response include the status of any workflow run for the user.
+    pub(crate) return_workflow_status: Option<bool>,
+
+    /// If true, requests that workflow statuses include the route taken through each workflow.
+    pub(crate) return_route_info: Option<bool>,
 }
 
 impl From<ScoreOptions> for ScoreQueryParams {
@@ -94,6 +112,8 @@ impl From<ScoreOptions> for ScoreQueryParams {
         ScoreQueryParams {
             api_key: opts.api_key.unwrap_or_default(),
             abuse_types: opts.abuse_types,
+            return_workflow_status: opts.return_workflow_status,
+            return_route_info: opts.return_route_info,
         }
     }
 }
@@ -0,0 +1,231 @@
+use crate::{
+    events::{Event, EventOptions},
+    Client, HttpClient, SendHttpClient,
+};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
+use tokio::time::{timeout_at, Instant};
+
+/// Configuration for an [EventSink].
+#[derive(Clone, Debug)]
+pub struct SinkOptions {
+    /// The maximum number of events waiting to be sent. Events enqueued while the queue is full
+    /// are dropped.
+    pub capacity: usize,
+
+    /// The number of events that triggers a flush.
+    pub batch_size: usize,
+
+    /// The most events of a batch sent at once.
+    pub concurrency: usize,
+
+    /// The longest an event waits before its batch is flushed.
+    pub flush_interval: Duration,
+
+    /// Options used when tracking each event.
+    pub event_options: EventOptions,
+}
+
+impl Default for SinkOptions {
+    fn default() -> Self {
+        SinkOptions {
+            capacity: 10_000,
+            batch_size: 100,
+            concurrency: 8,
+            flush_interval: Duration::from_secs(1),
+            event_options: EventOptions::default(),
+        }
+    }
+}
+
+/// A queue of events sent to Sift in batches by a background task.
+///
+/// [EventSink::enqueue] returns immediately; a task spawned on the current tokio runtime flushes
+/// the queue whenever `batch_size` events are waiting or the oldest has waited for
+/// `flush_interval`. Sift has no batch events endpoint, so a flush sends each event with
+/// [Client::track], `concurrency` at a time. Failed events are logged, and only retried when
+/// [EventOptions::retry] is set.
+///
+/// [EventSink::new] spawns the task with `tokio::spawn`, and requires a [SendHttpClient].
+/// [EventSink::new_local] spawns it with `tokio::task::spawn_local` instead, so that clients whose
+/// futures are not `Send`, like `awc`, can be used from within a `LocalSet`.
+///
+/// Only available with the `sink` feature.
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run(event: sift_science::events::Event) {
+/// use sift_science::{Client, EventSink, SinkOptions};
+/// use std::sync::Arc;
+///
+/// let sift = Arc::new(Client::new("api_key", reqwest::Client::new()));
+/// let sink = EventSink::new(sift, SinkOptions::default());
+///
+/// sink.enqueue(event);
+///
+/// // Flush what is still queued before exiting
+/// sink.shutdown().await;
+/// # }
+/// ```
+pub struct EventSink {
+    sender: mpsc::Sender<Event>,
+    dropped: Arc<AtomicU64>,
+    flusher: JoinHandle<()>,
+}
+
+impl EventSink {
+    /// Start a sink that sends events with the given client, flushing from a task spawned with
+    /// `tokio::spawn`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime, or if `capacity` or `batch_size` is zero.
+    pub fn new<T>(client: Arc<Client<T>>, options: SinkOptions) -> Self
+    where
+        T: SendHttpClient + 'static,
+    {
+        let (sender, receiver) = channel(&options);
+        let flusher = tokio::spawn(flush(client, receiver, options));
+
+        EventSink::start(sender, flusher)
+    }
+
+    /// Start a sink that sends events with the given client, flushing from a task spawned with
+    /// `tokio::task::spawn_local`.
+    ///
+    /// Use this for clients whose futures are not `Send`, like `awc`, from within a `LocalSet`
+    /// such as the one actix runs on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a `LocalSet`, or if `capacity` or `batch_size` is zero.
+    pub fn new_local<T>(client: Arc<Client<T>>, options: SinkOptions) -> Self
+    where
+        T: HttpClient + 'static,
+    {
+        let (sender, receiver) = channel(&options);
+        let flusher = tokio::task::spawn_local(flush(client, receiver, options));
+
+        EventSink::start(sender, flusher)
+    }
+
+    fn start(sender: mpsc::Sender<Event>, flusher: JoinHandle<()>) -> Self {
+        EventSink {
+            sender,
+            dropped: Arc::new(AtomicU64::new(0)),
+            flusher,
+        }
+    }
+
+    /// Queue an event to be sent, without waiting for it to be sent.
+    ///
+    /// Returns `false` if the event was dropped, either because it failed [Event::validate] or
+    /// the queue is full. Drops caused by a full queue are counted by [EventSink::dropped].
+    pub fn enqueue(&self, event: Event) -> bool {
+        if let Err(err) = event.validate() {
+            tracing::warn!(?err, "dropping invalid event");
+            return false;
+        }
+
+        match self.sender.try_send(event) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                tracing::warn!(dropped, "event sink queue is full, dropping event");
+                false
+            }
+            Err(TrySendError::Closed(_)) => {
+                tracing::error!("event sink flusher has stopped, dropping event");
+                false
+            }
+        }
+    }
+
+    /// The number of events dropped so far because the queue was full.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Stop accepting events, and wait for the queued events to be sent.
+    pub async fn shutdown(self) {
+        drop(self.sender);
+
+        if let Err(err) = self.flusher.await {
+            tracing::error!(?err, "event sink flusher failed");
+        }
+    }
+}
+
+impl fmt::Debug for EventSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventSink")
+            .field("dropped", &self.dropped())
+            .finish()
+    }
+}
+
+// Create the queue of a sink, checking its options
+fn channel(options: &SinkOptions) -> (mpsc::Sender<Event>, mpsc::Receiver<Event>) {
+    assert!(
+        options.batch_size > 0,
+        "batch_size must be greater than zero"
+    );
+    mpsc::channel(options.capacity)
+}
+
+// Receive events until the sink shuts down, sending them in batches
+async fn flush<T: HttpClient>(
+    client: Arc<Client<T>>,
+    mut receiver: mpsc::Receiver<Event>,
+    options: SinkOptions,
+) {
+    let mut batch = Vec::with_capacity(options.batch_size);
+    let mut deadline = None;
+
+    loop {
+        // `None` when the flush interval has passed
+        let received = match deadline {
+            Some(deadline) => timeout_at(deadline, receiver.recv()).await.ok(),
+            None => Some(receiver.recv().await),
+        };
+
+        match received {
+            Some(Some(event)) => {
+                if batch.is_empty() {
+                    deadline = Some(Instant::now() + options.flush_interval);
+                }
+                batch.push(event);
+
+                if batch.len() < options.batch_size {
+                    continue;
+                }
+            }
+            Some(None) => {
+                // The sink has shut down and the queue is drained
+                send(&client, &mut batch, &options).await;
+                return;
+            }
+            None => {}
+        }
+
+        send(&client, &mut batch, &options).await;
+        deadline = None;
+    }
+}
+
+// Send and clear a batch of events, logging failures
+async fn send<T: HttpClient>(client: &Client<T>, batch: &mut Vec<Event>, options: &SinkOptions) {
+    use futures::stream::{self, StreamExt};
+
+    stream::iter(std::mem::take(batch))
+        .for_each_concurrent(options.concurrency.max(1), |event| async move {
+            if let Err(err) = client.track(event, options.event_options.clone()).await {
+                tracing::error!(?err, "failed to send queued event");
+            }
+        })
+        .await;
+}
@@ -13,6 +13,7 @@
 use crate::{
     common::{deserialize_ms, deserialize_opt_ms, serialize_ms, serialize_opt_ms},
     events::{App, Browser, VerificationReason, VerificationType, VerifiedEvent},
+    Error, Result,
 };
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -114,8 +115,101 @@ pub struct SendRequestEvent {
     pub app: Option<App>,
 }
 
+impl SendRequestEvent {
+    /// The event for verifying a login, identified by its session.
+    pub fn login(session_id: impl Into<String>) -> Self {
+        let session_id = session_id.into();
+        SendRequestEvent {
+            verified_entity_id: Some(session_id.clone()),
+            ..SendRequestEvent::new(session_id, VerifiedEvent::Login)
+        }
+    }
+
+    /// The event for verifying an order, identified by its order ID.
+    pub fn create_order(session_id: impl Into<String>, order_id: impl Into<String>) -> Self {
+        SendRequestEvent {
+            verified_entity_id: Some(order_id.into()),
+            ..SendRequestEvent::new(session_id, VerifiedEvent::CreateOrder)
+        }
+    }
+
+    /// The event for verifying new content, identified by its content ID.
+    pub fn create_content(session_id: impl Into<String>, content_id: impl Into<String>) -> Self {
+        SendRequestEvent {
+            verified_entity_id: Some(content_id.into()),
+            ..SendRequestEvent::new(session_id, VerifiedEvent::CreateContent)
+        }
+    }
+
+    /// The event for verifying a password update, which needs no entity ID.
+    pub fn update_password(session_id: impl Into<String>) -> Self {
+        SendRequestEvent::new(session_id, VerifiedEvent::UpdatePassword)
+    }
+
+    /// The event for verifying any reserved event, without an entity ID or optional fields.
+    pub fn new(session_id: impl Into<String>, verified_event: VerifiedEvent) -> Self {
+        SendRequestEvent {
+            session_id: session_id.into(),
+            verified_event,
+            verified_entity_id: None,
+            ip: None,
+            reason: None,
+            browser: None,
+            app: None,
+        }
+    }
+
+    /// Checks that `verified_entity_id` is set when the verified event
+    /// [requires one](VerifiedEvent::requires_entity_id).
+    ///
+    /// Called by [Client::send_verification] before the request is sent.
+    ///
+    /// ```
+    /// use sift_science::{
+    ///     events::VerifiedEvent,
+    ///     verification::SendRequestEvent,
+    ///     Error,
+    /// };
+    ///
+    /// let event = SendRequestEvent::create_order("gigtleqddo84l8cm15qe4il", "ORDER-28168441");
+    /// assert_eq!(event.verified_entity_id.as_deref(), Some("ORDER-28168441"));
+    /// assert!(event.validate().is_ok());
+    ///
+    /// let event = SendRequestEvent::create_content("gigtleqddo84l8cm15qe4il", "9671500641");
+    /// assert_eq!(event.verified_entity_id.as_deref(), Some("9671500641"));
+    /// assert!(event.validate().is_ok());
+    ///
+    /// let event = SendRequestEvent::login("gigtleqddo84l8cm15qe4il");
+    /// assert_eq!(event.verified_entity_id.as_deref(), Some("gigtleqddo84l8cm15qe4il"));
+    /// assert!(event.validate().is_ok());
+    ///
+    /// let event = SendRequestEvent::update_password("gigtleqddo84l8cm15qe4il");
+    /// assert!(event.validate().is_ok());
+    /// assert!(serde_json::to_value(&event)
+    ///     .unwrap()
+    ///     .get("$verified_entity_id")
+    ///     .is_none());
+    ///
+    /// let event = SendRequestEvent::new("gigtleqddo84l8cm15qe4il", VerifiedEvent::CreateOrder);
+    /// assert!(matches!(event.validate(), Err(Error::Validation(_))));
+    /// ```
+    ///
+    /// [Client::send_verification]: crate::Client::send_verification
+    pub fn validate(&self) -> Result<()> {
+        if self.verified_event.requires_entity_id() && self.verified_entity_id.is_none() {
+            return Err(Error::Validation(format!(
+                "$verified_entity_id is required to verify {:?}",
+                self.verified_event
+            )));
+        }
+
+        Ok(())
+    }
+}
+
 /// Send verification response
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct SendResponse {
     /// The success or error code (see [relevant error codes]).
     ///
@@ -148,6 +242,32 @@ pub struct SendResponse {
     pub segment_name: Option<String>,
 }
 
+/// Options that may be passed when sending a verification
+#[derive(Debug, Default)]
+pub struct SendOptions {
+    /// Overrides the timeout for this call.
+    pub timeout: Option<Duration>,
+
+    /// Overrides the version of the Verification API to call.
+    pub version: Option<ApiVersion>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
+}
+
+/// Options that may be passed when re-sending a verification
+#[derive(Debug, Default)]
+pub struct ResendOptions {
+    /// Overrides the timeout for this call.
+    pub timeout: Option<Duration>,
+
+    /// Overrides the version of the Verification API to call.
+    pub version: Option<ApiVersion>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
+}
+
 /// Options that may be passed when checking a verification
 #[derive(Debug, Default)]
 pub struct CheckOptions {
@@ -162,6 +282,9 @@ pub struct CheckOptions {
 
     /// Overrides the version of the Events API to call.
     pub version: Option<ApiVersion>,
+
+    /// Extra HTTP headers to send with this call.
+    pub extra_headers: Option<Vec<(String, String)>>,
 }
 
 #[skip_serializing_none]
@@ -187,7 +310,39 @@ pub(crate) struct CheckRequest {
 }
 
 /// Check verification response
+///
+/// When a check fails, `attempts_remaining` and `reason` tell whether the user can try again or
+/// has to wait, when Sift sends them.
+///
+/// ```
+/// use sift_science::verification::CheckResponse;
+///
+/// let response: CheckResponse = serde_json::from_value(serde_json::json!({
+///     "status": 60,
+///     "error_message": "Too many attempts, please try again later",
+///     "checked_at": 1566324368003u64,
+///     "attempts_remaining": 0,
+///     "reason": "rate_limited"
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(response.status, 60);
+/// assert_eq!(response.attempts_remaining, Some(0));
+/// assert_eq!(response.reason.as_deref(), Some("rate_limited"));
+///
+/// let response: CheckResponse = serde_json::from_value(serde_json::json!({
+///     "status": 0,
+///     "error_message": "OK",
+///     "checked_at": 1566324368003u64
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(response.attempts_remaining, None);
+/// assert_eq!(response.reason, None);
+/// ```
+#[skip_serializing_none]
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct CheckResponse {
     /// The success or error code (see [relevant error codes]).
     ///
@@ -200,12 +355,24 @@ pub struct CheckResponse {
     /// The time the OTP verified.
     #[serde(deserialize_with = "deserialize_ms", serialize_with = "serialize_ms")]
     pub checked_at: SystemTime,
+
+    /// How many more times the user may enter a code before being rate limited.
+    #[serde(default)]
+    pub attempts_remaining: Option<u32>,
+
+    /// Why a check failed, e.g. a wrong code or too many attempts.
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 /// Verification API version
-#[derive(Copy, Clone, Debug)]
+///
+/// Defaults to the latest version supported by this library.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ApiVersion {
     /// Version 1
+    #[default]
     V1,
 }
 
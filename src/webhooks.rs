@@ -3,9 +3,11 @@
 //! When one of the events is triggered, Sift will send a JSON payload to the webhook's specified
 //! URL. Webhooks can be used to update your own support tool, data warehouses, and more.
 
-use crate::common::deserialize_ms;
-use crate::error::Error;
-use serde::{Deserialize, Serialize};
+use crate::common::{deserialize_ms, AbuseType};
+use crate::decisions::{Decision, DecisionData, EntityType};
+use crate::error::{Error, Result};
+use crate::events::Event;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt;
 use std::time::SystemTime;
 
@@ -14,6 +16,323 @@ use std::time::SystemTime;
 /// This should be used to verify that the request originates from Sift's servers.
 pub const SIGNATURE_HEADER: &str = "X-Sift-Science-Signature";
 
+/// Verify that a webhook invocation originates from Sift's servers.
+///
+/// `header_value` is the value of the [SIGNATURE_HEADER] header, e.g. `sha1=<hex digest>`, and
+/// `raw_body` is the request body exactly as it was received. The HMAC comparison is constant
+/// time.
+///
+/// ```
+/// use sift_science::webhooks::verify_signature;
+///
+/// let body = br#"{"entity":{"type":"user","id":"billy_jones_301"},"decision":{"id":"block_user_payment_abuse"},"time":1461963439151}"#;
+/// let signature = "sha1=edf4839594e1e5c428431ab0c81765464df0e39b";
+///
+/// assert!(verify_signature("ZjN2NzY4ZjE4MmE5", body, signature));
+/// assert!(!verify_signature("another_secret", body, signature));
+/// assert!(!verify_signature("ZjN2NzY4ZjE4MmE5", b"{}", signature));
+/// assert!(!verify_signature("ZjN2NzY4ZjE4MmE5", body, "edf4839594e1e5c428431ab0c81765464df0e39b"));
+/// ```
+pub fn verify_signature(secret: &str, raw_body: &[u8], header_value: &str) -> bool {
+    check_signature(secret, raw_body, header_value).is_ok()
+}
+
+pub(crate) fn check_signature(secret: &str, raw_body: &[u8], header_value: &str) -> Result<()> {
+    use hmac::{Hmac, Mac};
+
+    match header_value.split_once('=') {
+        Some(("sha1", tag)) if tag.is_ascii() && tag.len() == 40 => {
+            let mut mac = Hmac::<sha1::Sha1>::new_from_slice(secret.as_bytes())
+                .map_err(|err| Error::Server(err.to_string()))?;
+
+            mac.update(raw_body);
+
+            // parse hex string
+            let hex = (0..20).fold([0; 20], |mut acc, i| {
+                acc[i] = u8::from_str_radix(&tag[(i * 2)..=(i * 2 + 1)], 16).unwrap_or(0);
+                acc
+            });
+
+            mac.verify_slice(&hex)
+                .map_err(|err| Error::InvalidSignature(err.to_string()))
+        }
+        Some((alg, _)) => Err(Error::InvalidSignature(format!(
+            "unsupported type: {}",
+            alg
+        ))),
+        None => Err(Error::InvalidSignature("Invalid signature value".into())),
+    }
+}
+
+/// Headers of an inbound webhook invocation, used to look up the [SIGNATURE_HEADER].
+///
+/// Implemented for a plain header value (`str` or `String`), slices of `(name, value)` pairs,
+/// and `http::HeaderMap` with the `http` feature. Header names are matched case-insensitively.
+pub trait WebhookHeaders {
+    /// The value of the [SIGNATURE_HEADER] header, if present.
+    fn signature(&self) -> Option<&str>;
+}
+
+impl WebhookHeaders for str {
+    fn signature(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl WebhookHeaders for String {
+    fn signature(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> WebhookHeaders for [(K, V)] {
+    fn signature(&self) -> Option<&str> {
+        self.iter()
+            .find(|(name, _)| name.as_ref().eq_ignore_ascii_case(SIGNATURE_HEADER))
+            .map(|(_, value)| value.as_ref())
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> WebhookHeaders for Vec<(K, V)> {
+    fn signature(&self) -> Option<&str> {
+        self.as_slice().signature()
+    }
+}
+
+#[cfg(feature = "http")]
+impl WebhookHeaders for http::HeaderMap {
+    fn signature(&self) -> Option<&str> {
+        self.get(SIGNATURE_HEADER)?.to_str().ok()
+    }
+}
+
+/// Verify and parse an inbound webhook invocation.
+///
+/// The signature is checked before the body is parsed, so unsigned bodies are never parsed.
+///
+/// # Errors
+///
+/// Returns an [Error::InvalidSignature] if the [SIGNATURE_HEADER] is missing or doesn't match the
+/// body, and an [Error::Deserialize] if the signed body isn't a valid payload.
+///
+/// ```
+/// use hmac::{Hmac, Mac};
+/// use sift_science::{webhooks, Error};
+///
+/// let secret = "ZjN2NzY4ZjE4MmE5";
+/// let body = br#"{"entity":{"type":"user","id":"billy_jones_301"},"decision":{"id":"block_user_payment_abuse"},"time":1461963439151}"#;
+/// let headers = [("x-sift-science-signature", "sha1=edf4839594e1e5c428431ab0c81765464df0e39b")];
+///
+/// let payload = webhooks::handle(secret, &headers[..], body).unwrap();
+/// assert_eq!(payload.entity_id(), "billy_jones_301");
+///
+/// # #[cfg(feature = "http")]
+/// # {
+/// let mut header_map = http::HeaderMap::new();
+/// header_map.insert(webhooks::SIGNATURE_HEADER, headers[0].1.parse().unwrap());
+/// let payload = webhooks::handle(secret, &header_map, body).unwrap();
+/// assert_eq!(payload.decision_id(), "block_user_payment_abuse");
+/// # }
+///
+/// // A bad or missing signature is rejected
+/// assert!(matches!(
+///     webhooks::handle("another_secret", &headers[..], body),
+///     Err(Error::InvalidSignature(_))
+/// ));
+/// assert!(matches!(
+///     webhooks::handle(secret, &Vec::<(String, String)>::new(), body),
+///     Err(Error::InvalidSignature(_))
+/// ));
+///
+/// // A correctly signed but malformed body fails to parse
+/// let body = b"{\"entity\": ";
+/// let mut mac = Hmac::<sha1::Sha1>::new_from_slice(secret.as_bytes()).unwrap();
+/// mac.update(body);
+/// let digest = mac.finalize().into_bytes();
+/// let signature = format!(
+///     "sha1={}",
+///     digest.iter().map(|b| format!("{:02x}", b)).collect::<String>()
+/// );
+///
+/// assert!(matches!(
+///     webhooks::handle(secret, signature.as_str(), body),
+///     Err(Error::Deserialize(_))
+/// ));
+/// ```
+pub fn handle<H>(secret: &str, headers: &H, raw_body: &[u8]) -> Result<WebhookPayload>
+where
+    H: WebhookHeaders + ?Sized,
+{
+    let signature = headers
+        .signature()
+        .ok_or_else(|| Error::InvalidSignature(format!("missing {} header", SIGNATURE_HEADER)))?;
+
+    check_signature(secret, raw_body, signature)?;
+    parse_payload(raw_body)
+}
+
+/// Parse the body of an inbound [PayloadType::DecisionV10] webhook invocation.
+///
+/// The signature should be checked with [verify_signature] first.
+///
+/// ```
+/// use sift_science::{
+///     decisions::EntityType,
+///     webhooks::{parse_payload, WebhookPayload},
+///     AbuseType,
+/// };
+///
+/// // The example payload from Sift's decision webhook docs
+/// let body = br#"{
+///   "entity": {
+///     "type": "user",
+///     "id": "USER123"
+///   },
+///   "decision": {
+///     "id": "block_user_payment_abuse"
+///   },
+///   "time": 1461963439151
+/// }"#;
+///
+/// let payload = parse_payload(body).unwrap();
+/// assert!(matches!(payload, WebhookPayload::Decision(_)));
+/// assert_eq!(payload.entity_type(), EntityType::User);
+/// assert_eq!(payload.entity_id(), "USER123");
+/// assert_eq!(payload.decision_id(), "block_user_payment_abuse");
+/// assert!(matches!(payload.abuse_type(), Some(AbuseType::PaymentAbuse)));
+///
+/// // Decisions on orders are told apart by their entity type
+/// let body = br#"{
+///   "entity": { "type": "order", "id": "ORDER-28168441" },
+///   "decision": { "id": "hold_order_for_review" },
+///   "time": 1461963439151
+/// }"#;
+///
+/// let payload = parse_payload(body).unwrap();
+/// assert!(matches!(payload, WebhookPayload::OrderDecision(_)));
+/// assert_eq!(payload.entity_id(), "ORDER-28168441");
+///
+/// // The abuse type can't be told from a custom decision id
+/// assert!(payload.abuse_type().is_none());
+/// ```
+pub fn parse_payload(body: &[u8]) -> Result<WebhookPayload> {
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// The payload of an inbound [PayloadType::DecisionV10] webhook invocation.
+///
+/// Sift sends the applied decision, and the variant is chosen by the type of its entity.
+/// [PayloadType::OrderV10] webhooks send the [order object] instead, which is not parsed here.
+///
+/// See <https://sift.com/developers/docs/curl/decisions-api/decision-webhooks>
+///
+/// [order object]: https://sift.com/developers/docs/curl/orders-api/order
+#[derive(Debug)]
+pub enum WebhookPayload {
+    /// A decision applied to an order.
+    OrderDecision(Decision),
+
+    /// A decision applied to a user, session or content.
+    Decision(Decision),
+}
+
+impl WebhookPayload {
+    /// The applied decision.
+    pub fn decision(&self) -> &Decision {
+        match self {
+            WebhookPayload::OrderDecision(decision) | WebhookPayload::Decision(decision) => {
+                decision
+            }
+        }
+    }
+
+    /// The id of the entity the decision was applied to.
+    pub fn entity_id(&self) -> &str {
+        &self.decision().entity.id
+    }
+
+    /// The type of entity the decision was applied to.
+    pub fn entity_type(&self) -> EntityType {
+        self.decision().entity.entity_type
+    }
+
+    /// The id of the applied decision.
+    pub fn decision_id(&self) -> &str {
+        &self.decision().decision.id
+    }
+
+    /// The abuse type of the applied decision, guessed from its id.
+    ///
+    /// The payload does not carry the abuse type. This returns `None` unless the id ends with the
+    /// abuse type, as Sift's default decision ids do (e.g. `block_user_payment_abuse`). Use
+    /// [WebhookPayload::abuse_type_in] to look it up for custom decisions.
+    pub fn abuse_type(&self) -> Option<AbuseType> {
+        let id = self.decision_id();
+        std::iter::once(0)
+            .chain(id.match_indices('_').map(|(i, _)| i + 1))
+            .find_map(|start| AbuseType::from_wire(&id[start..]))
+    }
+
+    /// The abuse type of the applied decision, looked up in the decisions configured for the
+    /// account, e.g. from [Client::list_all_decisions].
+    ///
+    /// ```
+    /// use sift_science::{decisions::DecisionData, webhooks::parse_payload, AbuseType};
+    ///
+    /// let payload = parse_payload(
+    ///     br#"{
+    ///       "entity": { "type": "order", "id": "ORDER-28168441" },
+    ///       "decision": { "id": "hold_order_for_review" },
+    ///       "time": 1461963439151
+    ///     }"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let decisions: Vec<DecisionData> = serde_json::from_value(serde_json::json!([{
+    ///     "id": "hold_order_for_review",
+    ///     "name": "Hold order for review",
+    ///     "entity_type": "order",
+    ///     "abuse_type": "payment_abuse",
+    ///     "category": "WATCH",
+    ///     "created_at": 1461963439151u64,
+    ///     "updated_at": 1461963439151u64
+    /// }]))
+    /// .unwrap();
+    ///
+    /// assert!(matches!(
+    ///     payload.abuse_type_in(&decisions),
+    ///     Some(AbuseType::PaymentAbuse)
+    /// ));
+    /// assert!(payload.abuse_type_in(&[]).is_none());
+    /// ```
+    ///
+    /// [Client::list_all_decisions]: crate::Client::list_all_decisions
+    pub fn abuse_type_in(&self, decisions: &[DecisionData]) -> Option<AbuseType> {
+        decisions
+            .iter()
+            .find(|decision| decision.id == self.decision_id())
+            .map(|decision| decision.abuse_type)
+    }
+
+    /// The time the decision was applied.
+    pub fn time(&self) -> SystemTime {
+        self.decision().time
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookPayload {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let decision = Decision::deserialize(deserializer)?;
+        Ok(match decision.entity.entity_type {
+            EntityType::Order => WebhookPayload::OrderDecision(decision),
+            _ => WebhookPayload::Decision(decision),
+        })
+    }
+}
+
 /// Options when creating a new [Webhook].
 ///
 /// See <https://sift.com/developers/docs/curl/webhooks-api/create> for examples.
@@ -48,6 +367,7 @@ pub struct WebhookRequest {
 ///
 /// See <https://sift.com/developers/docs/curl/webhooks-api/create> for examples.
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct Webhook {
     /// The id of the webhook.
     pub id: u64,
@@ -88,13 +408,40 @@ pub struct Webhook {
 }
 
 /// The type of webhook payload.
+///
+/// ```
+/// use sift_science::webhooks::{PayloadType, Webhook};
+///
+/// assert_eq!(serde_json::json!(PayloadType::OrderV10), "ORDER_V1_0");
+/// assert_eq!(serde_json::json!(PayloadType::DecisionV10), "DECISION_V1_0");
+///
+/// let webhook: Webhook = serde_json::from_value(serde_json::json!({
+///     "id": 1,
+///     "payload_type": "DECISION_V1_0",
+///     "status": "ACTIVE",
+///     "url": "https://example.com/sift",
+///     "enabled_events": ["$create_order"],
+///     "created": 1461963439151u64,
+///     "last_updated": 1461963439151u64,
+/// }))
+/// .unwrap();
+/// assert!(matches!(webhook.payload_type, PayloadType::DecisionV10));
+/// assert_eq!(serde_json::json!(webhook)["payload_type"], "DECISION_V1_0");
+/// ```
 #[derive(Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum PayloadType {
     /// This payload type provides an order data response.
     ///
     /// See the [order object](https://sift.com/developers/docs/curl/orders-api/order).
     #[serde(rename = "ORDER_V1_0")]
     OrderV10,
+
+    /// This payload type provides a decision data response.
+    ///
+    /// See [WebhookPayload] for the payload contents.
+    #[serde(rename = "DECISION_V1_0")]
+    DecisionV10,
 }
 
 /// The webhook status.
@@ -117,7 +464,35 @@ pub enum Status {
 /// These correspond to the Reserved Events in the [Events API].
 ///
 /// [Events API]: https://sift.com/developers/docs/curl/events-api/overview
+///
+/// ```
+/// use sift_science::webhooks::{EnabledEvent, PayloadType, Status, WebhookRequest};
+///
+/// let request = WebhookRequest {
+///     payload_type: PayloadType::OrderV10,
+///     status: Status::Active,
+///     url: "https://example.com/sift".into(),
+///     enabled_events: vec![
+///         EnabledEvent::CreateAccount,
+///         EnabledEvent::UpdateAccount,
+///         EnabledEvent::Login,
+///         EnabledEvent::Other("$future_event".into()),
+///     ],
+///     name: None,
+///     description: None,
+/// };
+/// assert_eq!(
+///     serde_json::json!(request)["enabled_events"],
+///     serde_json::json!(["$create_account", "$update_account", "$login", "$future_event"])
+/// );
+///
+/// let events: Vec<EnabledEvent> =
+///     serde_json::from_str(r#"["$transaction", "$future_event"]"#).unwrap();
+/// assert!(matches!(events[0], EnabledEvent::Transaction));
+/// assert!(matches!(&events[1], EnabledEvent::Other(event) if event == "$future_event"));
+/// ```
 #[derive(Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum EnabledEvent {
     /// Occurs whenever a [Event::CreateOrder] event is tracked.
     ///
@@ -148,6 +523,161 @@ pub enum EnabledEvent {
     /// [Event::Chargeback]: crate::events::Event::Chargeback
     #[serde(rename = "$chargeback")]
     Chargeback,
+
+    /// Occurs whenever a [Event::AddItemToCart] event is tracked.
+    ///
+    /// [Event::AddItemToCart]: crate::events::Event::AddItemToCart
+    #[serde(rename = "$add_item_to_cart")]
+    AddItemToCart,
+
+    /// Occurs whenever a [Event::AddPromotion] event is tracked.
+    ///
+    /// [Event::AddPromotion]: crate::events::Event::AddPromotion
+    #[serde(rename = "$add_promotion")]
+    AddPromotion,
+
+    /// Occurs whenever a [Event::ContentStatus] event is tracked.
+    ///
+    /// [Event::ContentStatus]: crate::events::Event::ContentStatus
+    #[serde(rename = "$content_status")]
+    ContentStatus,
+
+    /// Occurs whenever a [Event::CreateAccount] event is tracked.
+    ///
+    /// [Event::CreateAccount]: crate::events::Event::CreateAccount
+    #[serde(rename = "$create_account")]
+    CreateAccount,
+
+    /// Occurs whenever a [Event::CreateContent] event is tracked.
+    ///
+    /// [Event::CreateContent]: crate::events::Event::CreateContent
+    #[serde(rename = "$create_content")]
+    CreateContent,
+
+    /// Occurs whenever a [Event::FlagContent] event is tracked.
+    ///
+    /// [Event::FlagContent]: crate::events::Event::FlagContent
+    #[serde(rename = "$flag_content")]
+    FlagContent,
+
+    /// Occurs whenever a [Event::LinkSessionToUser] event is tracked.
+    ///
+    /// [Event::LinkSessionToUser]: crate::events::Event::LinkSessionToUser
+    #[serde(rename = "$link_session_to_user")]
+    LinkSessionToUser,
+
+    /// Occurs whenever a [Event::Login] event is tracked.
+    ///
+    /// [Event::Login]: crate::events::Event::Login
+    #[serde(rename = "$login")]
+    Login,
+
+    /// Occurs whenever a [Event::Logout] event is tracked.
+    ///
+    /// [Event::Logout]: crate::events::Event::Logout
+    #[serde(rename = "$logout")]
+    Logout,
+
+    /// Occurs whenever a [Event::RemoveItemFromCart] event is tracked.
+    ///
+    /// [Event::RemoveItemFromCart]: crate::events::Event::RemoveItemFromCart
+    #[serde(rename = "$remove_item_from_cart")]
+    RemoveItemFromCart,
+
+    /// Occurs whenever a [Event::SecurityNotification] event is tracked.
+    ///
+    /// [Event::SecurityNotification]: crate::events::Event::SecurityNotification
+    #[serde(rename = "$security_notification")]
+    SecurityNotification,
+
+    /// Occurs whenever a [Event::UpdateAccount] event is tracked.
+    ///
+    /// [Event::UpdateAccount]: crate::events::Event::UpdateAccount
+    #[serde(rename = "$update_account")]
+    UpdateAccount,
+
+    /// Occurs whenever a [Event::UpdateContent] event is tracked.
+    ///
+    /// [Event::UpdateContent]: crate::events::Event::UpdateContent
+    #[serde(rename = "$update_content")]
+    UpdateContent,
+
+    /// Occurs whenever a [Event::UpdatePassword] event is tracked.
+    ///
+    /// [Event::UpdatePassword]: crate::events::Event::UpdatePassword
+    #[serde(rename = "$update_password")]
+    UpdatePassword,
+
+    /// Occurs whenever a [Event::Verification] event is tracked.
+    ///
+    /// [Event::Verification]: crate::events::Event::Verification
+    #[serde(rename = "$verification")]
+    Verification,
+
+    /// An event not known to this version of the library.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Webhooks can be enabled for every reserved event except [Event::Label].
+///
+/// ```
+/// use sift_science::{
+///     events::{Event, LabelProperties, OrderProperties},
+///     webhooks::EnabledEvent,
+///     AbuseType, Error,
+/// };
+///
+/// let event = Event::CreateOrder {
+///     user_id: Some("billy_jones_301".into()),
+///     properties: OrderProperties::default(),
+/// };
+/// assert!(matches!(
+///     EnabledEvent::try_from(&event),
+///     Ok(EnabledEvent::CreateOrder)
+/// ));
+///
+/// let event = Event::Label {
+///     is_fraud: true,
+///     abuse_type: AbuseType::PaymentAbuse,
+///     properties: LabelProperties::default(),
+/// };
+/// assert!(matches!(
+///     EnabledEvent::try_from(&event),
+///     Err(Error::Validation(_))
+/// ));
+/// ```
+impl TryFrom<&Event> for EnabledEvent {
+    type Error = Error;
+
+    fn try_from(event: &Event) -> Result<Self> {
+        match event {
+            Event::AddItemToCart { .. } => Ok(EnabledEvent::AddItemToCart),
+            Event::AddPromotion { .. } => Ok(EnabledEvent::AddPromotion),
+            Event::Chargeback { .. } => Ok(EnabledEvent::Chargeback),
+            Event::ContentStatus { .. } => Ok(EnabledEvent::ContentStatus),
+            Event::CreateAccount { .. } => Ok(EnabledEvent::CreateAccount),
+            Event::CreateContent { .. } => Ok(EnabledEvent::CreateContent),
+            Event::CreateOrder { .. } => Ok(EnabledEvent::CreateOrder),
+            Event::FlagContent { .. } => Ok(EnabledEvent::FlagContent),
+            Event::LinkSessionToUser { .. } => Ok(EnabledEvent::LinkSessionToUser),
+            Event::Login { .. } => Ok(EnabledEvent::Login),
+            Event::Logout { .. } => Ok(EnabledEvent::Logout),
+            Event::OrderStatus { .. } => Ok(EnabledEvent::OrderStatus),
+            Event::RemoveItemFromCart { .. } => Ok(EnabledEvent::RemoveItemFromCart),
+            Event::SecurityNotification { .. } => Ok(EnabledEvent::SecurityNotification),
+            Event::Transaction { .. } => Ok(EnabledEvent::Transaction),
+            Event::UpdateAccount { .. } => Ok(EnabledEvent::UpdateAccount),
+            Event::UpdateContent { .. } => Ok(EnabledEvent::UpdateContent),
+            Event::UpdateOrder { .. } => Ok(EnabledEvent::UpdateOrder),
+            Event::UpdatePassword { .. } => Ok(EnabledEvent::UpdatePassword),
+            Event::Verification { .. } => Ok(EnabledEvent::Verification),
+            Event::Label { .. } => Err(Error::Validation(format!(
+                "webhooks can't be enabled for {} events",
+                event.reserved_type()
+            ))),
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -165,9 +695,13 @@ pub(crate) enum WebhookResponse {
 }
 
 /// Webhook API version
-#[derive(Copy, Clone, Debug)]
+///
+/// Defaults to the latest version supported by this library.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ApiVersion {
     /// Version 3
+    #[default]
     V3,
 }
 
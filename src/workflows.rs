@@ -0,0 +1,48 @@
+//! Look up Sift workflow runs.
+//!
+//! Workflows run when events are tracked, e.g. to apply decisions or send users to a review queue.
+//! The status of the runs triggered by an event can be returned synchronously with
+//! [EventOptions::return_workflow_status](crate::events::EventOptions::return_workflow_status),
+//! and a run can be looked up by its id later with
+//! [Client::get_workflow_run](crate::Client::get_workflow_run).
+//!
+//! <https://sift.com/developers/docs/curl/workflows-api/overview>
+
+use crate::{events::WorkflowStatus, Error};
+use serde::Deserialize;
+use std::fmt;
+
+pub use crate::events::{WorkflowConfig, WorkflowEntity, WorkflowHistory};
+
+/// A workflow run, with its state, the entity it ran for and the apps it ran.
+///
+/// The same shape as the [WorkflowStatus] returned when tracking an event.
+///
+/// <https://sift.com/developers/docs/curl/workflows-api/workflow-status>
+pub type WorkflowRun = WorkflowStatus;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum WorkflowRunResult {
+    Error(Error),
+    Run(WorkflowRun),
+}
+
+/// Workflows API version
+///
+/// Defaults to the latest version supported by this library.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// Version 3
+    #[default]
+    V3,
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiVersion::V3 => write!(f, "v3"),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+mod common;
+
+use common::{login, mock_client, ok, ACCOUNT_ID, ORIGIN, SESSION_ID, USER_ID};
+use serde_json::json;
+use sift_science::{
+    events::{CreateAccountProperties, Event, EventOptions, LoginProperties},
+    AbuseType, Client, Method, Metrics, MockHttpClient, QueryParams, RequestEnd, SendHttpClient,
+};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[tokio::test]
+async fn clones_share_the_http_client() {
+    let sift = mock_client();
+    let clone = sift.clone();
+
+    assert_eq!(clone.api_key, "api_key");
+    assert_eq!(clone.account_id.as_deref(), Some(ACCOUNT_ID));
+    assert_eq!(clone.origin, ORIGIN);
+
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+    clone
+        .track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(sift.http_client.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn map_http_client_keeps_the_configuration() {
+    let mocked = MockHttpClient::new();
+    let sift = mock_client().map_http_client(|_| mocked);
+
+    assert_eq!(sift.api_key, "api_key");
+    assert_eq!(sift.account_id.as_deref(), Some(ACCOUNT_ID));
+    assert_eq!(sift.origin, ORIGIN);
+
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+    sift.http_client
+        .assert_posted_to("https://sift.example.com/v205/events");
+}
+
+#[tokio::test]
+async fn builder() {
+    let sift = Client::builder("api_key", MockHttpClient::new())
+        .account_id(ACCOUNT_ID)
+        .origin(ORIGIN)
+        .default_timeout(Duration::from_secs(5))
+        .abuse_types(vec![AbuseType::AccountTakeover])
+        .build();
+
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+
+    let request = &sift.http_client.requests()[0];
+    assert_eq!(request.url, "https://sift.example.com/v205/events");
+    assert_eq!(request.timeout, Duration::from_secs(5));
+}
+
+#[cfg(feature = "score")]
+#[tokio::test]
+async fn default_abuse_types() {
+    use sift_science::score::ScoreOptions;
+
+    let sift = Client::builder("api_key", MockHttpClient::new())
+        .abuse_types(vec![AbuseType::PaymentAbuse, AbuseType::AccountTakeover])
+        .build();
+
+    // The default applies when a call leaves `abuse_types` unset
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+    sift.http_client.respond_with(Method::Get, ok());
+    sift.get_user_score(USER_ID, ScoreOptions::default())
+        .await
+        .unwrap();
+
+    // A call's own abuse types win
+    let options = EventOptions {
+        abuse_types: Some(vec![AbuseType::ContentAbuse]),
+        ..Default::default()
+    };
+    sift.track(login(USER_ID), options).await.unwrap();
+
+    let queries = sift
+        .http_client
+        .requests()
+        .into_iter()
+        .map(|request| request.query.unwrap_or_default())
+        .collect::<Vec<_>>();
+    assert!(queries[0].contains("abuse_types=payment_abuse%2Caccount_takeover"));
+    assert!(queries[1].contains("abuse_types=payment_abuse%2Caccount_takeover"));
+    assert!(queries[2].contains("abuse_types=content_abuse"));
+    assert!(!queries[2].contains("payment_abuse"));
+
+    // Without a default or per-call abuse types, none are sent
+    let sift = Client::new("api_key", MockHttpClient::new());
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+    assert!(!sift.http_client.requests()[0]
+        .query
+        .as_deref()
+        .unwrap_or_default()
+        .contains("abuse_types"));
+}
+
+#[test]
+fn build_event_request() {
+    let mut sift = Client::new("api_key", MockHttpClient::new());
+    sift.default_abuse_types = Some(vec![AbuseType::AccountAbuse]);
+
+    let (url, body, query_params) = sift
+        .build_event_request(
+            Event::CreateAccount {
+                user_id: USER_ID.into(),
+                session_id: Some(SESSION_ID.into()),
+                properties: Box::new(CreateAccountProperties {
+                    user_email: Some("bill@gmail.com".into()),
+                    name: Some("Bill Jones".into()),
+                    ..Default::default()
+                }),
+            },
+            EventOptions {
+                return_score: Some(true),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(url, "https://api.sift.com/v205/events");
+    assert_eq!(
+        body,
+        json!({
+            "$type": "$create_account",
+            "$user_id": USER_ID,
+            "$session_id": SESSION_ID,
+            "$user_email": "bill@gmail.com",
+            "$name": "Bill Jones",
+            "$api_key": "api_key"
+        })
+    );
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "return_score=true&abuse_types=account_abuse"
+    );
+    assert!(sift.http_client.requests().is_empty());
+}
+
+#[test]
+fn query_string() {
+    let query_params = QueryParams::from(EventOptions {
+        return_score: Some(true),
+        abuse_types: Some(vec![AbuseType::PaymentAbuse]),
+        ..Default::default()
+    });
+
+    assert_eq!(
+        query_params.to_query_string().unwrap(),
+        "return_score=true&abuse_types=payment_abuse"
+    );
+}
+
+#[derive(Clone, Default)]
+struct Logs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Logs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn redact_pii() {
+    let logs = Logs::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let sift = Client::builder("api_key", MockHttpClient::new())
+        .redact_pii(true)
+        .build();
+
+    tracing::subscriber::with_default(subscriber, || {
+        futures::executor::block_on(sift.track(
+            Event::Login {
+                user_id: USER_ID.into(),
+                session_id: None,
+                properties: LoginProperties {
+                    user_email: Some("bill@gmail.com".into()),
+                    ..Default::default()
+                },
+            },
+            EventOptions {
+                api_key: Some("secret_key".into()),
+                ..Default::default()
+            },
+        ))
+        .unwrap();
+    });
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("[REDACTED]"));
+    assert!(!logs.contains("bill@gmail.com"));
+
+    // API keys are never logged
+    assert!(!logs.contains("secret_key"));
+
+    // The request sent to Sift is unchanged
+    let body = &sift.http_client.bodies()[0];
+    assert_eq!(body["$user_email"], "bill@gmail.com");
+    assert_eq!(body["$api_key"], "secret_key");
+}
+
+#[test]
+fn send_http_client_futures_are_send() {
+    fn assert_send<T: Send>(_: T) {}
+
+    async fn track<T: SendHttpClient>(sift: &Client<T>) {
+        let _ = sift.track(login(USER_ID), EventOptions::default()).await;
+    }
+
+    let sift = Client::new("api_key", MockHttpClient::new());
+    assert_send(track(&sift));
+}
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<(&'static str, bool)>>);
+
+impl Metrics for Recorder {
+    fn on_request_end(&self, end: RequestEnd<'_>) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((end.endpoint, end.status.is_ok()));
+    }
+}
+
+#[tokio::test]
+async fn metrics() {
+    let recorder = Arc::new(Recorder::default());
+    let sift = Client::new("api_key", MockHttpClient::new()).with_metrics(recorder.clone());
+
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(*recorder.0.lock().unwrap(), vec![("track", true)]);
+}
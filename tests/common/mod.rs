@@ -0,0 +1,53 @@
+//! Fixtures shared by the integration tests
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+use sift_science::{
+    events::{Event, LoginProperties},
+    Client, MockHttpClient, MockResponse,
+};
+
+pub const USER_ID: &str = "billy_jones_301";
+pub const SESSION_ID: &str = "gigtleqddo84l8cm15qe4il";
+pub const ORIGIN: &str = "https://sift.example.com";
+pub const ACCOUNT_ID: &str = "account_id";
+
+/// A login event for the given user, without a session or properties
+pub fn login(user_id: &str) -> Event {
+    Event::Login {
+        user_id: user_id.into(),
+        session_id: None,
+        properties: LoginProperties::default(),
+    }
+}
+
+/// A client sending through a [MockHttpClient], with a fixed origin and account id
+pub fn mock_client() -> Client<MockHttpClient> {
+    Client::new("api_key", MockHttpClient::new())
+        .with_origin(ORIGIN)
+        .with_account_id(ACCOUNT_ID)
+}
+
+/// The body of a successful Sift response
+pub fn ok() -> Value {
+    json!({ "status": 0, "error_message": "OK" })
+}
+
+/// A successful Sift response, as served by a `MockServer`
+pub fn ok_response() -> MockResponse {
+    MockResponse::json(200, &ok().to_string())
+}
+
+/// A decision as listed by the Decisions API
+pub fn decision(id: &str, entity_type: &str) -> Value {
+    json!({
+        "id": id,
+        "entity_type": entity_type,
+        "abuse_type": "payment_abuse",
+        "category": "BLOCK",
+        "created_at": 1468005577348u64,
+        "created_by": "admin@example.com",
+        "updated_at": 1469229177756u64,
+        "updated_by": "analyst@example.com"
+    })
+}
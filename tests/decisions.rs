@@ -0,0 +1,214 @@
+mod common;
+
+use common::{decision, mock_client, USER_ID};
+use futures::TryStreamExt;
+use serde_json::json;
+use sift_science::{
+    decisions::{ApiVersion, DecisionRequest, Entity, EntityType, ListDecisionsOptions, Source},
+    AbuseType, Client, Error, Method, MockHttpClient,
+};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn block_user(time: Option<SystemTime>) -> DecisionRequest {
+    DecisionRequest {
+        decision_id: "block_user_payment_abuse".into(),
+        source: Source::AutomatedRule,
+        analyst: None,
+        time,
+        description: None,
+    }
+}
+
+fn decisions_page(ids: &[&str], entity_type: &str, has_more: bool) -> serde_json::Value {
+    json!({
+        "data": ids.iter().map(|id| decision(id, entity_type)).collect::<Vec<_>>(),
+        "has_more": has_more,
+        "schema": "decisions",
+        "total_results": 4
+    })
+}
+
+#[tokio::test]
+async fn apply_decision_paths() {
+    let sift = mock_client();
+
+    let user_id = || USER_ID.to_string();
+    let entities = [
+        (
+            Entity::User { user_id: user_id() },
+            "users/billy_jones_301",
+            "user",
+        ),
+        (
+            Entity::Order {
+                user_id: user_id(),
+                order_id: "ORDER-28168441".into(),
+            },
+            "users/billy_jones_301/orders/ORDER-28168441",
+            "order",
+        ),
+        (
+            Entity::Session {
+                user_id: user_id(),
+                session_id: "gigtleqddo84l8cm15qe4il".into(),
+            },
+            "users/billy_jones_301/sessions/gigtleqddo84l8cm15qe4il",
+            "session",
+        ),
+        (
+            Entity::Content {
+                user_id: user_id(),
+                content_id: "listing-23412".into(),
+            },
+            "users/billy_jones_301/content/listing-23412",
+            "content",
+        ),
+    ];
+
+    for (i, (entity, path, entity_type)) in entities.into_iter().enumerate() {
+        sift.http_client.respond_with(
+            Method::Post,
+            json!({
+                "entity": { "id": USER_ID, "type": entity_type },
+                "decision": { "id": "block_user_payment_abuse" },
+                "time": 1461963439151u64
+            }),
+        );
+
+        let decision = sift.apply_decision(entity, block_user(None)).await.unwrap();
+        assert_eq!(decision.decision.id, "block_user_payment_abuse");
+
+        assert_eq!(
+            sift.http_client.requests()[i].url,
+            format!(
+                "https://sift.example.com/v3/accounts/account_id/{}/decisions",
+                path
+            )
+        );
+    }
+}
+
+#[tokio::test]
+async fn decision_times() {
+    let time = |time| serde_json::to_value(block_user(Some(time)));
+
+    let valid = UNIX_EPOCH + Duration::from_millis(1461963439151);
+    assert_eq!(time(valid).unwrap()["time"], 1461963439151u64);
+
+    let max = UNIX_EPOCH + Duration::from_millis(u64::MAX);
+    assert_eq!(time(max).unwrap()["time"], u64::MAX);
+
+    let overflow = max + Duration::from_millis(1);
+    assert!(time(overflow).is_err());
+    assert!(time(UNIX_EPOCH - Duration::from_secs(1)).is_err());
+
+    // The client reports unrepresentable times without sending the request
+    let sift = mock_client();
+    let entity = Entity::User {
+        user_id: USER_ID.into(),
+    };
+    let err = sift
+        .apply_decision(entity, block_user(Some(overflow)))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Validation(_)));
+    assert!(sift.http_client.requests().is_empty());
+}
+
+#[tokio::test]
+async fn list_decisions_abuse_types() {
+    let sift = mock_client();
+
+    for (abuse_types, query) in [
+        (
+            vec![AbuseType::PaymentAbuse],
+            "entity_type=user&abuse_types=payment_abuse",
+        ),
+        (
+            vec![AbuseType::PaymentAbuse, AbuseType::PromoAbuse],
+            "entity_type=user&abuse_types=payment_abuse%2Cpromotion_abuse",
+        ),
+    ] {
+        let _ = sift
+            .list_decisions(ListDecisionsOptions {
+                entity_type: Some(EntityType::User),
+                abuse_types: Some(abuse_types),
+                ..Default::default()
+            })
+            .await;
+
+        let requests = sift.http_client.requests();
+        assert_eq!(requests.last().unwrap().query.as_deref(), Some(query));
+    }
+}
+
+#[tokio::test]
+async fn list_decisions_api_version() {
+    let sift = Client::new("api_key", MockHttpClient::new()).with_account_id("5c9e6c1b8bd9b44e");
+    let _ = sift.list_decisions(ListDecisionsOptions::default()).await;
+
+    assert_eq!(ApiVersion::default(), ApiVersion::V3);
+    sift.http_client
+        .assert_requested(Method::Get, "/v3/accounts/5c9e6c1b8bd9b44e/decisions");
+}
+
+#[tokio::test]
+async fn list_all_decisions() {
+    let sift = mock_client();
+    sift.http_client
+        .respond_with(
+            Method::Get,
+            decisions_page(&["block_order", "hold_order"], "order", true),
+        )
+        .respond_with(
+            Method::Get,
+            decisions_page(&["review_order", "accept_order"], "order", false),
+        );
+
+    let decisions = sift
+        .list_all_decisions(ListDecisionsOptions {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let ids = decisions.iter().map(|d| d.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec!["block_order", "hold_order", "review_order", "accept_order"]
+    );
+
+    let queries = sift
+        .http_client
+        .requests()
+        .into_iter()
+        .map(|req| req.query.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(queries, vec!["limit=2&from=0", "limit=2&from=2"]);
+}
+
+#[tokio::test]
+async fn decisions_stream() {
+    let sift = mock_client();
+    sift.http_client
+        .respond_with(
+            Method::Get,
+            decisions_page(&["block_user", "watch_user"], "user", true),
+        )
+        .respond_with(Method::Get, decisions_page(&["accept_user"], "user", false));
+
+    let decisions = sift
+        .decisions_stream(ListDecisionsOptions::default())
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    let ids = decisions.iter().map(|d| d.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["block_user", "watch_user", "accept_user"]);
+
+    let requests = sift.http_client.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].query.as_deref(), Some("from=0"));
+    assert_eq!(requests[1].query.as_deref(), Some("from=2"));
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use common::{login, USER_ID};
+use serde_json::json;
+use sift_science::{events::EventOptions, Client, Error, Method, MockHttpClient, SiftErrorCode};
+
+#[tokio::test]
+async fn track_errors() {
+    let sift = Client::new("api_key", MockHttpClient::new());
+
+    // Sift reported an error for the request
+    sift.http_client.respond_with(
+        Method::Post,
+        json!({ "status": 51, "error_message": "Invalid API key" }),
+    );
+    let err = sift
+        .track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Request {
+            status: SiftErrorCode::InvalidApiKey,
+            ..
+        }
+    ));
+
+    // The request never reached Sift
+    sift.http_client
+        .fail_with(Method::Post, Error::Transport("connection refused".into()));
+    let err = sift
+        .track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Transport(_)));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[cfg(feature = "score")]
+#[tokio::test]
+async fn unexpected_response_shape() {
+    use sift_science::score::ScoreOptions;
+
+    let sift = Client::new("api_key", MockHttpClient::new());
+    sift.http_client
+        .respond_with(Method::Get, json!(["not", "a", "score"]));
+
+    let err = sift
+        .get_user_score(USER_ID, ScoreOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Deserialize(_)));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[cfg(feature = "score")]
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    use sift_science::{score::ScoreOptions, RetryPolicy};
+
+    let bad_request = || Error::Http {
+        status: 400,
+        body: "Bad Request".into(),
+    };
+    let bad_gateway = Error::Http {
+        status: 502,
+        body: "Bad Gateway".into(),
+    };
+    assert!(!bad_request().is_retryable());
+    assert!(bad_gateway.is_retryable());
+
+    let http_client = MockHttpClient::new();
+    http_client.fail_with(Method::Get, bad_request());
+    let sift = Client::new("api_key", http_client).with_retry_policy(RetryPolicy::default());
+
+    let err = sift
+        .get_user_score(USER_ID, ScoreOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Http { status: 400, .. }));
+    assert_eq!(sift.http_client.requests().len(), 1);
+}
+
+#[cfg(feature = "reqwest")]
+mod reqwest_errors {
+    use sift_science::{
+        Error, HttpClient, MockResponse, MockServer, QueryParams, ReqwestClient, SiftErrorCode,
+    };
+    use std::{net::TcpListener, time::Duration};
+
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    fn login() -> serde_json::Value {
+        serde_json::json!({ "$type": "$login" })
+    }
+
+    #[tokio::test]
+    async fn http_errors() {
+        let server = MockServer::start(vec![
+            MockResponse::raw(
+                "HTTP/1.1 502 Bad Gateway\r\nContent-Type: text/html\r\n\
+                 Content-Length: 22\r\nConnection: close\r\n\r\n<h1>Bad Gateway</h1>\r\n",
+            ),
+            MockResponse::json(400, r#"{"status":51,"error_message":"Invalid API Key"}"#),
+            MockResponse::raw(
+                "HTTP/1.1 503 Service Unavailable\r\nContent-Type: text/html\r\n\
+                 Content-Length: 30\r\nConnection: close\r\n\r\n<h1>Service Unavailable</h1>\r\n",
+            ),
+        ]);
+        let sift = ReqwestClient::new("api_key", reqwest::Client::new());
+        let url = format!("{}/v205/events", server.origin());
+        let body = login();
+        let post = || {
+            HttpClient::post(
+                &sift.http_client,
+                &url,
+                None,
+                Some(&body),
+                TIMEOUT,
+                None,
+                &[],
+            )
+        };
+
+        let err = post().await.unwrap_err();
+        assert!(matches!(
+            &err,
+            Error::Http { status: 502, body } if body == "<h1>Bad Gateway</h1>"
+        ));
+        assert!(err.is_retryable());
+
+        let err = post().await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                status: SiftErrorCode::InvalidApiKey,
+                ..
+            }
+        ));
+
+        // GET requests fail the same way
+        let query_params = QueryParams::default();
+        let err = HttpClient::get(&sift.http_client, &url, &query_params, TIMEOUT, None, &[])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            &err,
+            Error::Http { status: 503, body } if body == "<h1>Service Unavailable</h1>"
+        ));
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn rate_limited() {
+        let server = MockServer::start(vec![
+            MockResponse::raw(
+                "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 2\r\n\
+                 Content-Length: 9\r\nConnection: close\r\n\r\nSlow down",
+            ),
+            MockResponse::raw(
+                "HTTP/1.1 429 Too Many Requests\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            ),
+        ]);
+        let sift = ReqwestClient::new("api_key", reqwest::Client::new());
+        let url = format!("{}/v205/events", server.origin());
+        let body = login();
+        let post = || {
+            HttpClient::post(
+                &sift.http_client,
+                &url,
+                None,
+                Some(&body),
+                TIMEOUT,
+                None,
+                &[],
+            )
+        };
+
+        let err = post().await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after: Some(retry_after)
+            } if retry_after == Duration::from_secs(2)
+        ));
+
+        let err = post().await.unwrap_err();
+        assert!(matches!(err, Error::RateLimited { retry_after: None }));
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        // Accept connections, but never respond
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v205/events", listener.local_addr().unwrap());
+
+        let sift = ReqwestClient::new("api_key", reqwest::Client::new());
+        let body = login();
+        let timeout = Duration::from_millis(100);
+
+        let err = HttpClient::post(
+            &sift.http_client,
+            &url,
+            None,
+            Some(&body),
+            timeout,
+            None,
+            &[],
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, Error::Timeout(_)));
+        assert!(err.is_retryable());
+        drop(listener);
+    }
+}
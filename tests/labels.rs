@@ -0,0 +1,117 @@
+mod common;
+
+use common::{mock_client, ok, USER_ID};
+use serde_json::json;
+use sift_science::{
+    labels::{LabelManyOptions, LabelOptions, LabelProperties},
+    AbuseType, Client, Error, Method, MockHttpClient,
+};
+
+fn fraud() -> LabelProperties {
+    LabelProperties {
+        is_fraud: true,
+        abuse_type: AbuseType::PaymentAbuse,
+        description: None,
+        source: None,
+        analyst: None,
+        extra: None,
+    }
+}
+
+#[tokio::test]
+async fn label_many() {
+    let http_client = MockHttpClient::new();
+    http_client
+        .respond_with(Method::Post, ok())
+        .respond_with(
+            Method::Post,
+            json!({ "status": 51, "error_message": "Invalid API key" }),
+        )
+        .respond_with(Method::Post, ok())
+        .fail_with(Method::Post, Error::Server("connection reset".into()))
+        .respond_with(Method::Post, ok());
+    let sift = Client::new("api_key", http_client);
+
+    let items = (0..5).map(|i| (format!("user_{}", i), fraud())).collect();
+    let results = sift
+        .label_many(
+            items,
+            LabelManyOptions {
+                concurrency: 2,
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(Error::Request { .. })));
+    assert!(results[2].is_ok());
+    assert!(matches!(results[3], Err(Error::Server(_))));
+    assert!(results[4].is_ok());
+
+    assert_eq!(sift.http_client.requests().len(), 5);
+    assert_eq!(sift.http_client.max_concurrent_requests(), 2);
+}
+
+#[tokio::test]
+async fn unlabel_user() {
+    let sift = Client::new("api_key", MockHttpClient::new());
+
+    sift.unlabel_user(
+        "billy jones",
+        AbuseType::PaymentAbuse,
+        LabelOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    let request = &sift.http_client.requests()[0];
+    assert_eq!(request.method, Method::Delete);
+    assert_eq!(
+        request.url,
+        "https://api.sift.com/v205/users/billy%20jones/labels\
+         ?abuse_type=payment_abuse&api_key=api_key"
+    );
+}
+
+#[cfg(feature = "decisions")]
+#[tokio::test]
+async fn label_or_decide() {
+    use sift_science::labels::LabelRoute;
+
+    let sift = mock_client();
+    sift.http_client
+        .respond_with(Method::Post, ok())
+        .respond_with(
+            Method::Post,
+            json!({
+                "entity": { "id": USER_ID, "type": "user" },
+                "decision": { "id": "block_user_payment_abuse" },
+                "time": 1461963439151u64
+            }),
+        );
+
+    for use_decisions in [false, true] {
+        sift.label_or_decide(
+            USER_ID,
+            fraud(),
+            LabelRoute::from_flag(use_decisions),
+            LabelOptions::default(),
+        )
+        .await
+        .unwrap();
+    }
+
+    let requests = sift.http_client.requests();
+    assert_eq!(
+        requests[0].url,
+        "https://sift.example.com/v205/users/billy_jones_301/labels"
+    );
+    assert_eq!(
+        requests[1].url,
+        "https://sift.example.com/v3/accounts/account_id/users/billy_jones_301/decisions"
+    );
+    let decision = requests[1].body.as_ref().unwrap();
+    assert_eq!(decision["decision_id"], "block_user_payment_abuse");
+    assert_eq!(decision["source"], "AUTOMATED_RULE");
+}
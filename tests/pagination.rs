@@ -0,0 +1,38 @@
+use futures::{executor::block_on, TryStreamExt};
+use sift_science::{Page, Paginator, Result};
+
+struct Numbers {
+    numbers: Vec<u32>,
+    has_more: bool,
+}
+
+impl Page for Numbers {
+    type Item = u32;
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    fn into_items(self) -> Vec<u32> {
+        self.numbers
+    }
+}
+
+#[test]
+fn stops_before_the_offset_overflows() {
+    let mut offsets = Vec::new();
+    let numbers = Paginator::new(u32::MAX - 3, |from| {
+        offsets.push(from);
+        async move {
+            Result::Ok(Numbers {
+                numbers: vec![from; 2],
+                has_more: true,
+            })
+        }
+    })
+    .into_stream();
+
+    let numbers: Vec<u32> = block_on(numbers.try_collect()).unwrap();
+    assert_eq!(numbers.len(), 4);
+    assert_eq!(offsets, vec![u32::MAX - 3, u32::MAX - 1]);
+}
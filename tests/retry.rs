@@ -0,0 +1,87 @@
+mod common;
+
+use common::{login, USER_ID};
+use sift_science::{events::EventOptions, Client, Error, Method, MockHttpClient, RetryPolicy};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn rate_limited(retry_after: Option<Duration>) -> Error {
+    Error::RateLimited { retry_after }
+}
+
+#[tokio::test]
+async fn events_are_retried_when_opted_in() {
+    let policy = RetryPolicy {
+        base_delay: Duration::ZERO,
+        ..Default::default()
+    };
+    let sift = Client::new("api_key", MockHttpClient::new()).with_retry_policy(policy);
+    let connection_reset = || Error::Transport("connection reset".into());
+
+    // Events are sent once by default
+    sift.http_client.fail_with(Method::Post, connection_reset());
+    assert!(sift
+        .track(login(USER_ID), EventOptions::default())
+        .await
+        .is_err());
+    assert_eq!(sift.http_client.requests().len(), 1);
+
+    // and retried when opted in
+    sift.http_client.fail_with(Method::Post, connection_reset());
+    sift.track(login(USER_ID), EventOptions::default().with_retry())
+        .await
+        .unwrap();
+    assert_eq!(sift.http_client.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn retries_wait_for_the_retry_delay() {
+    let delays = Arc::new(Mutex::new(Vec::new()));
+    let recorded = delays.clone();
+    let policy = RetryPolicy {
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_secs(10),
+        jitter: false,
+        ..Default::default()
+    }
+    .with_sleep(move |delay| {
+        recorded.lock().unwrap().push(delay);
+        Box::pin(futures::future::ready(()))
+    });
+
+    let http_client = MockHttpClient::new();
+    http_client.fail_with(Method::Post, rate_limited(Some(Duration::from_secs(5))));
+    http_client.fail_with(Method::Post, rate_limited(None));
+    let sift = Client::new("api_key", http_client).with_retry_policy(policy);
+
+    sift.track(login(USER_ID), EventOptions::default().with_retry())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *delays.lock().unwrap(),
+        vec![Duration::from_secs(5), Duration::from_millis(200)]
+    );
+}
+
+#[cfg(any(feature = "hyper", feature = "reqwest", feature = "sink"))]
+#[tokio::test(start_paused = true)]
+async fn retries_sleep_on_the_tokio_timer() {
+    // A paused tokio clock only skips ahead for sleeps on the tokio timer
+    let policy = RetryPolicy {
+        base_delay: Duration::from_secs(3600),
+        max_delay: Duration::from_secs(3600),
+        jitter: false,
+        ..Default::default()
+    };
+    let sift = Client::new("api_key", MockHttpClient::new()).with_retry_policy(policy);
+    sift.http_client
+        .fail_with(Method::Post, Error::Transport("connection reset".into()));
+
+    let started = tokio::time::Instant::now();
+    sift.track(login(USER_ID), EventOptions::default().with_retry())
+        .await
+        .unwrap();
+
+    assert_eq!(started.elapsed(), Duration::from_secs(3600));
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use common::{mock_client, ok, SESSION_ID, USER_ID};
+use serde_json::json;
+use sift_science::{score::ScoreOptions, AbuseType, Client, Method, MockHttpClient};
+
+#[tokio::test]
+async fn get_user_score() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Get,
+        json!({
+            "status": 0,
+            "error_message": "OK",
+            "entity_id": USER_ID,
+            "scores": { "legacy": { "score": 0.42, "reasons": [] } }
+        }),
+    );
+    let sift = Client::new("api_key", http_client);
+
+    let response = sift
+        .get_user_score(
+            USER_ID,
+            ScoreOptions {
+                abuse_types: Some(vec![AbuseType::Legacy]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let scores = response.scores.unwrap();
+    assert_eq!(scores.legacy.as_ref().unwrap().score, 0.42);
+    assert_eq!(scores.get(AbuseType::Legacy).unwrap().score, 0.42);
+    assert!(sift.http_client.requests()[0]
+        .query
+        .as_deref()
+        .unwrap()
+        .contains("abuse_types=legacy"));
+}
+
+#[tokio::test]
+async fn get_user_score_path() {
+    let sift = mock_client();
+    sift.http_client.respond_with(Method::Get, ok());
+
+    sift.get_user_score(
+        USER_ID,
+        ScoreOptions {
+            path_prefix: Some("accounts"),
+            path_suffix: Some("risk"),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        sift.http_client.requests()[0].url,
+        "https://sift.example.com/v205/accounts/billy_jones_301/risk"
+    );
+}
+
+#[tokio::test]
+async fn get_user_score_workflow_statuses() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Get,
+        json!({
+            "status": 0,
+            "error_message": "OK",
+            "scores": { "payment_abuse": { "score": 0.9, "reasons": [] } },
+            "workflow_statuses": [{
+                "id": "6dbq76qbaaaaa",
+                "config": { "version": "1468367620871" },
+                "config_display_name": "create order route",
+                "abuse_types": ["payment_abuse"],
+                "state": "finished",
+                "entity": { "id": USER_ID, "type": "user" },
+                "history": [{ "app": "user_scorer", "name": "Entity Scored", "state": "finished" }]
+            }]
+        }),
+    );
+    let sift = Client::new("api_key", http_client);
+
+    let score = sift
+        .get_user_score(
+            USER_ID,
+            ScoreOptions {
+                return_workflow_status: Some(true),
+                return_route_info: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let query = sift.http_client.requests()[0].query.clone().unwrap();
+    assert!(query.contains("return_workflow_status=true"));
+    assert!(query.contains("return_route_info=true"));
+
+    let workflow_statuses = score.workflow_statuses.unwrap();
+    assert_eq!(workflow_statuses[0].state, "finished");
+    assert_eq!(workflow_statuses[0].history[0].app, "user_scorer");
+}
+
+#[tokio::test]
+async fn get_session_score() {
+    let sift = mock_client();
+    sift.http_client.respond_with(
+        Method::Get,
+        json!({
+            "status": 0,
+            "error_message": "OK",
+            "entity_id": SESSION_ID,
+            "scores": { "payment_abuse": { "score": 0.31, "reasons": [] } }
+        }),
+    );
+
+    let response = sift
+        .get_session_score(
+            SESSION_ID,
+            ScoreOptions {
+                abuse_types: Some(vec![AbuseType::PaymentAbuse]),
+                return_workflow_status: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.scores.unwrap().payment_abuse.unwrap().score, 0.31);
+
+    let request = &sift.http_client.requests()[0];
+    assert_eq!(
+        request.url,
+        "https://sift.example.com/v205/sessions/gigtleqddo84l8cm15qe4il/score"
+    );
+    let query = request.query.as_deref().unwrap();
+    assert!(query.contains("api_key=api_key"));
+    assert!(query.contains("abuse_types=payment_abuse"));
+    assert!(query.contains("return_workflow_status=true"));
+}
+
+#[tokio::test]
+async fn rescore_session() {
+    let sift = mock_client();
+    sift.http_client.respond_with(
+        Method::Post,
+        json!({
+            "status": 0,
+            "error_message": "OK",
+            "entity_id": SESSION_ID,
+            "scores": { "account_abuse": { "score": 0.12, "reasons": [] } }
+        }),
+    );
+
+    sift.rescore_session(
+        SESSION_ID,
+        ScoreOptions {
+            abuse_types: Some(vec![AbuseType::AccountAbuse]),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    sift.http_client
+        .assert_requested(Method::Post, "/v205/sessions/gigtleqddo84l8cm15qe4il/score");
+    assert!(sift.http_client.requests()[0]
+        .query
+        .as_deref()
+        .unwrap()
+        .contains("abuse_types=account_abuse"));
+}
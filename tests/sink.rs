@@ -0,0 +1,62 @@
+mod common;
+
+use common::login;
+use sift_science::{Client, EventSink, MockHttpClient, SinkOptions};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn full_batches_are_sent_right_away() {
+    let sift = Arc::new(Client::new("api_key", MockHttpClient::new()));
+    let sink = EventSink::new(
+        sift.clone(),
+        SinkOptions {
+            batch_size: 2,
+            flush_interval: Duration::from_secs(60),
+            ..Default::default()
+        },
+    );
+
+    for user_id in ["a", "b", "c"] {
+        assert!(sink.enqueue(login(user_id)));
+    }
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(sift.http_client.bodies().len(), 2);
+
+    // and the rest on shutdown
+    sink.shutdown().await;
+    let mut user_ids = sift
+        .http_client
+        .bodies()
+        .iter()
+        .map(|body| body["$user_id"].as_str().unwrap().to_string())
+        .collect::<Vec<_>>();
+    user_ids.sort();
+    assert_eq!(user_ids, vec!["a", "b", "c"]);
+}
+
+#[tokio::test]
+async fn partial_batches_are_sent_after_the_flush_interval() {
+    let local = tokio::task::LocalSet::new();
+    local
+        .run_until(async {
+            let sift = Arc::new(Client::new("api_key", MockHttpClient::new()));
+            let sink = EventSink::new_local(
+                sift.clone(),
+                SinkOptions {
+                    batch_size: 100,
+                    flush_interval: Duration::from_millis(50),
+                    ..Default::default()
+                },
+            );
+
+            assert!(sink.enqueue(login("a")));
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            assert_eq!(sift.http_client.bodies().len(), 1);
+            assert_eq!(sink.dropped(), 0);
+
+            sink.shutdown().await;
+            assert_eq!(sift.http_client.bodies().len(), 1);
+        })
+        .await;
+}
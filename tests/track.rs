@@ -0,0 +1,331 @@
+mod common;
+
+use common::{login, ok, USER_ID};
+use serde_json::{json, Value};
+use sift_science::{
+    events::{ApiVersion, EventOptions},
+    AbuseType, Client, Error, Method, MockHttpClient, QueryParams, SiftErrorCode,
+};
+use std::time::{Duration, UNIX_EPOCH};
+use tracing_test::traced_test;
+
+fn score_response(score_response: Value) -> Value {
+    json!({
+        "status": 0,
+        "error_message": "OK",
+        "score_response": score_response
+    })
+}
+
+#[traced_test]
+#[test]
+fn track_span_fields() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(Method::Post, ok());
+    let sift = Client::new("api_key", http_client);
+
+    futures::executor::block_on(sift.track(login(USER_ID), EventOptions::default())).unwrap();
+
+    assert!(logs_contain("endpoint=\"track\""));
+    assert!(logs_contain("api_version=v205"));
+    assert!(logs_contain("user=\""));
+    assert!(!logs_contain("user=\"\""));
+    assert!(!logs_contain("user_id=\"billy_jones_301\""));
+}
+
+#[traced_test]
+#[test]
+fn only_sent_events_are_logged_as_tracked() {
+    let sift = Client::new("api_key", MockHttpClient::new());
+
+    sift.build_event_request(login(USER_ID), EventOptions::default())
+        .unwrap();
+    assert!(!logs_contain("tracking event"));
+
+    futures::executor::block_on(sift.track(login(USER_ID), EventOptions::default())).unwrap();
+    assert!(logs_contain("tracking event"));
+}
+
+#[tokio::test]
+async fn track_scored() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Post,
+        score_response(json!({
+            "status": 0,
+            "error_message": "OK",
+            "scores": { "account_takeover": { "score": 0.12 } }
+        })),
+    );
+    http_client.respond_with(Method::Post, ok());
+    let sift = Client::new("api_key", http_client);
+
+    let scores = sift
+        .track_scored(login(USER_ID), vec![AbuseType::AccountTakeover])
+        .await
+        .unwrap();
+    assert!(scores.account_takeover.is_some());
+    assert!(sift.http_client.requests()[0]
+        .query
+        .as_deref()
+        .unwrap()
+        .contains("return_score=true"));
+
+    let err = sift
+        .track_scored(login(USER_ID), vec![AbuseType::AccountTakeover])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Server(_)));
+}
+
+#[tokio::test]
+async fn track_full_workflow_statuses() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Post,
+        score_response(json!({
+            "status": 0,
+            "error_message": "OK",
+            "scores": { "payment_abuse": { "score": 0.898391231245 } },
+            "workflow_statuses": [{
+                "id": "6dbq76qbaaaaa",
+                "config": { "version": "1468367620871" },
+                "config_display_name": "workflow config",
+                "abuse_types": ["payment_abuse"],
+                "state": "running",
+                "entity": { "id": USER_ID, "type": "user" },
+                "history": [{
+                    "app": "decision",
+                    "name": "ban user",
+                    "state": "running",
+                    "config": { "decision_id": "ban-user-payment-abuse" }
+                }]
+            }]
+        })),
+    );
+    let sift = Client::new("api_key", http_client);
+
+    let outcome = sift
+        .track_full(
+            login(USER_ID),
+            EventOptions {
+                return_workflow_status: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(outcome.scores.unwrap().payment_abuse.is_some());
+    assert!(outcome.actions.is_empty());
+    assert_eq!(outcome.workflow_statuses[0].state, "running");
+    assert_eq!(outcome.workflow_statuses[0].history[0].app, "decision");
+}
+
+#[tokio::test]
+async fn track_full_actions() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Post,
+        score_response(json!({
+            "status": 0,
+            "error_message": "OK",
+            "actions": [
+                {
+                    "action": { "id": "take_action" },
+                    "entity": { "id": USER_ID },
+                    "id": "5c3d9d1e4d6d6c8c0d2b6f2e:take_action",
+                    "time": 1461963439151u64,
+                    "triggers": [{
+                        "source": "synchronous_action",
+                        "trigger_id": "5c3d9d1e4d6d6c8c0d2b6f2f",
+                        "type": "formula"
+                    }]
+                },
+                {
+                    "action": { "id": "review_user" },
+                    "entity": { "id": USER_ID },
+                    "id": "5c3d9d1e4d6d6c8c0d2b6f30:review_user",
+                    "time": 1461963439152u64
+                }
+            ]
+        })),
+    );
+    let sift = Client::new("api_key", http_client);
+
+    let outcome = sift
+        .track_full(
+            login(USER_ID),
+            EventOptions {
+                return_action: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let actions = outcome
+        .actions
+        .iter()
+        .map(|action| action.action.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(actions, vec!["take_action", "review_user"]);
+
+    let action = &outcome.actions[0];
+    assert_eq!(action.entity.id, USER_ID);
+    assert_eq!(
+        action.time,
+        UNIX_EPOCH + Duration::from_millis(1461963439151)
+    );
+    assert_eq!(action.triggers[0].trigger_type, "formula");
+    assert_eq!(
+        action.triggers[0].source.as_deref(),
+        Some("synchronous_action")
+    );
+    assert!(outcome.actions[1].triggers.is_empty());
+}
+
+#[tokio::test]
+async fn track_raw() {
+    let response = json!({
+        "status": 0,
+        "error_message": "OK",
+        "time": 1327604222,
+        "request": "{ ... }",
+        "score_response": {
+            "status": 0,
+            "error_message": "OK",
+            "scores": { "account_takeover": { "score": 0.72, "reasons": [] } },
+            "new_field": { "not": "modeled" }
+        }
+    });
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(Method::Post, response.clone());
+    let sift = Client::new("api_key", http_client);
+
+    let raw = sift
+        .track_raw(
+            login(USER_ID),
+            EventOptions {
+                return_score: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(raw, response);
+    assert_eq!(raw["score_response"]["new_field"]["not"], "modeled");
+}
+
+#[tokio::test]
+async fn event_responses() {
+    let sift = Client::new("api_key", MockHttpClient::new());
+    let track = |respond_with: Option<Value>| {
+        match respond_with {
+            Some(body) => sift.http_client.respond_with(Method::Post, body),
+            None => sift.http_client.respond_with_empty(Method::Post),
+        };
+        sift.track(login(USER_ID), EventOptions::default())
+    };
+    let scores = || {
+        json!({
+            "status": 0,
+            "error_message": "OK",
+            "scores": { "payment_abuse": { "score": 0.89 } }
+        })
+    };
+
+    // No score requested, so no body
+    assert!(track(None).await.unwrap().is_none());
+
+    // Score requested
+    let scored = track(Some(score_response(scores()))).await.unwrap();
+    assert!(scored.unwrap().payment_abuse.is_some());
+
+    // The event was rejected, which takes precedence over the scores
+    let err = track(Some(json!({
+        "status": 51,
+        "error_message": "Invalid API Key",
+        "score_response": scores()
+    })))
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Request {
+            status: SiftErrorCode::InvalidApiKey,
+            ..
+        }
+    ));
+
+    // The event was accepted, but scoring failed
+    let err = track(Some(score_response(json!({
+        "status": 54,
+        "error_message": "Specified user_id has no scoreable events"
+    }))))
+    .await
+    .unwrap_err();
+    assert!(
+        matches!(err, Error::Request { ref error_message, .. } if error_message.contains("no scoreable events"))
+    );
+
+    // The event was accepted, and no scores were returned
+    assert!(track(Some(ok())).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn extra_headers() {
+    let sift = Client::new("api_key", MockHttpClient::new());
+
+    sift.track(
+        login(USER_ID),
+        EventOptions {
+            extra_headers: Some(vec![("X-Request-Id".into(), "req-1234".into())]),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        sift.http_client.requests()[0].headers,
+        vec![("X-Request-Id".to_string(), "req-1234".to_string())]
+    );
+}
+
+#[test]
+fn scored_options() {
+    let options = EventOptions::scored(vec![AbuseType::PaymentAbuse, AbuseType::AccountAbuse])
+        .with_timeout(Duration::from_secs(1));
+    assert_eq!(options.timeout, Some(Duration::from_secs(1)));
+
+    assert_eq!(
+        QueryParams::from(options).to_query_string().unwrap(),
+        "return_score=true&abuse_types=payment_abuse%2Caccount_abuse"
+    );
+}
+
+#[tokio::test]
+async fn api_version() {
+    let sift = Client::new("api_key", MockHttpClient::new());
+
+    sift.track(login(USER_ID), EventOptions::default())
+        .await
+        .unwrap();
+    sift.track(
+        login(USER_ID),
+        EventOptions {
+            version: Some(ApiVersion::V205),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(ApiVersion::default(), ApiVersion::V205);
+    for request in sift.http_client.requests() {
+        assert!(request.url.ends_with("/v205/events"), "{}", request.url);
+    }
+}
@@ -0,0 +1,409 @@
+//! Requests sent through each HTTP client to a local [MockServer](sift_science::MockServer)
+mod common;
+
+#[cfg(feature = "reqwest")]
+mod reqwest_client {
+    use crate::common::{login, ok_response, USER_ID};
+    use sift_science::{
+        events::EventOptions, Client, Error, MockResponse, MockServer, ReqwestClient,
+    };
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn track_with_meta() {
+        let body = r#"{"status":0,"error_message":"OK"}"#;
+        let server = MockServer::start(vec![
+            MockResponse::raw(format!(
+                "HTTP/1.1 200 OK\r\nX-RateLimit-Limit: 100\r\nX-RateLimit-Remaining: 99\r\n\
+                 Content-Type: application/json\r\nContent-Length: {}\r\n\
+                 Connection: close\r\n\r\n{body}",
+                body.len(),
+            )),
+            MockResponse::raw(
+                "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 2\r\nX-RateLimit-Remaining: 0\r\n\
+                 Content-Length: 0\r\nConnection: close\r\n\r\n",
+            ),
+        ]);
+        let sift = Client::new("api_key", reqwest::Client::new()).with_origin(server.origin());
+
+        let (scores, meta) = sift
+            .track_with_meta(login(USER_ID), EventOptions::default())
+            .await
+            .unwrap();
+        assert!(scores.is_none());
+        assert_eq!(meta.status, 200);
+        assert_eq!(meta.rate_limit_limit(), Some(100));
+        assert_eq!(meta.rate_limit_remaining(), Some(99));
+
+        let err = sift
+            .track_with_meta(login(USER_ID), EventOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after: Some(retry_after)
+            } if retry_after == Duration::from_secs(2)
+        ));
+    }
+
+    #[tokio::test]
+    async fn compress_requests() {
+        use flate2::read::GzDecoder;
+        use serde_json::json;
+        use sift_science::events::{Event, LoginProperties};
+        use std::io::Read;
+
+        let server = MockServer::start(vec![]);
+        let sift = Client::builder("api_key", reqwest::Client::new())
+            .origin(server.origin())
+            .compress_requests(true)
+            .compression_threshold(1024)
+            .build();
+
+        let login = |extra| Event::Login {
+            user_id: USER_ID.into(),
+            session_id: None,
+            properties: LoginProperties {
+                extra,
+                ..Default::default()
+            },
+        };
+
+        // Small bodies are sent as is
+        sift.track(login(None), EventOptions::default())
+            .await
+            .unwrap();
+        let request = server.next_request();
+        assert_eq!(request.header("Content-Encoding"), None);
+        let json: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(json["$user_id"], USER_ID);
+
+        // Large bodies are compressed
+        let notes = "x".repeat(2048);
+        sift.track(
+            login(Some(json!({ "notes": notes }))),
+            EventOptions::default(),
+        )
+        .await
+        .unwrap();
+        let request = server.next_request();
+        assert_eq!(request.header("Content-Encoding"), Some("gzip"));
+        assert!(request.body.len() < 1024);
+
+        let mut json = String::new();
+        GzDecoder::new(&request.body[..])
+            .read_to_string(&mut json)
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(json["notes"], notes);
+    }
+
+    #[tokio::test]
+    async fn content_encoding_header_does_not_compress() {
+        let server = MockServer::start(vec![]);
+        let sift = Client::builder("api_key", reqwest::Client::new())
+            .origin(server.origin())
+            .compression_threshold(0)
+            .build();
+
+        sift.track(
+            login(USER_ID),
+            EventOptions {
+                extra_headers: Some(vec![("Content-Encoding".into(), "gzip".into())]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        // Compression is off, so the header is passed through with a plain body
+        let request = server.next_request();
+        assert_eq!(request.header("Content-Encoding"), Some("gzip"));
+        let json: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(json["$user_id"], USER_ID);
+    }
+
+    #[tokio::test]
+    async fn tuned() {
+        let server = MockServer::start(vec![ok_response(), ok_response()]);
+        let sift = ReqwestClient::tuned("api_key")
+            .unwrap()
+            .with_origin(server.origin());
+
+        for user_id in [USER_ID, "jane_doe_302"] {
+            sift.track(login(user_id), EventOptions::default())
+                .await
+                .unwrap();
+
+            let request = server.next_request();
+            assert_eq!(request.request_line, "POST /v205/events HTTP/1.1");
+            assert!(request
+                .header("user-agent")
+                .unwrap()
+                .starts_with("sift-rust/"));
+        }
+    }
+
+    #[cfg(feature = "webhooks")]
+    #[tokio::test]
+    async fn delete_webhook() {
+        use sift_science::SiftErrorCode;
+
+        let server = MockServer::start(vec![
+            MockResponse::json(204, ""),
+            MockResponse::json(
+                404,
+                r#"{"error": "not_found", "description": "Webhook not found"}"#,
+            ),
+            MockResponse::json(
+                500,
+                r#"{"status": -1, "error_message": "Internal server error"}"#,
+            ),
+        ]);
+        let sift = Client::builder("api_key", reqwest::Client::new())
+            .origin(server.origin())
+            .account_id("account_id")
+            .build();
+
+        // Deleted
+        sift.delete_webhook(1).await.unwrap();
+
+        // Already deleted
+        sift.delete_webhook(1).await.unwrap();
+
+        let err = sift.delete_webhook(1).await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                status: SiftErrorCode::InternalServerError,
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "otel")]
+    #[tokio::test]
+    async fn propagates_trace_context() {
+        use opentelemetry::{
+            global,
+            trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+            Context,
+        };
+        use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+        let server = MockServer::start(vec![]);
+        let sift = Client::new("api_key", reqwest::Client::new()).with_origin(server.origin());
+
+        // Without a propagator, nothing is injected
+        sift.track(login(USER_ID), EventOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(server.next_request().header("traceparent"), None);
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let _guard = Context::current()
+            .with_remote_span_context(span_context)
+            .attach();
+
+        sift.track(login(USER_ID), EventOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            server.next_request().header("traceparent"),
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+        );
+    }
+}
+
+#[cfg(feature = "reqwest-middleware")]
+mod reqwest_middleware_client {
+    use crate::common::{login, ok_response, USER_ID};
+    use reqwest_middleware::{ClientBuilder, Middleware, Next};
+    use sift_science::{
+        events::EventOptions, Client, MockResponse, MockServer, ReqwestMiddlewareClient,
+    };
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use task_local_extensions::Extensions;
+
+    #[derive(Default)]
+    struct CountRequests(Arc<AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl Middleware for CountRequests {
+        async fn handle(
+            &self,
+            req: reqwest::Request,
+            extensions: &mut Extensions,
+            next: Next<'_>,
+        ) -> reqwest_middleware::Result<reqwest::Response> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            next.run(req, extensions).await
+        }
+    }
+
+    #[tokio::test]
+    async fn track() {
+        let server = MockServer::start(vec![
+            ok_response(),
+            MockResponse::raw("HTTP/1.1 204 No Content\r\nConnection: close\r\n\r\n"),
+        ]);
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let http_client = ClientBuilder::new(reqwest::Client::new())
+            .with(CountRequests(count.clone()))
+            .build();
+        let sift: ReqwestMiddlewareClient = Client::builder("api_key", http_client)
+            .origin(server.origin())
+            .build();
+
+        sift.track(login(USER_ID), EventOptions::default())
+            .await
+            .unwrap();
+        assert!(sift
+            .track(login(USER_ID), EventOptions::default())
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+}
+
+#[cfg(feature = "hyper")]
+mod hyper_client {
+    use sift_science::{Error, HttpClient, HyperClient, MockResponse, MockServer, SiftErrorCode};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn post() {
+        let server = MockServer::start(vec![
+            MockResponse::json(204, ""),
+            MockResponse::json(
+                500,
+                r#"{"status": -1, "error_message": "Internal server error"}"#,
+            ),
+        ]);
+
+        let sift = HyperClient::with_https("api_key");
+        let url = format!("{}/v205/events", server.origin());
+        let timeout = Duration::from_secs(2);
+        let body = serde_json::json!({ "$type": "$login" });
+
+        let res = sift
+            .http_client
+            .post(&url, None, Some(&body), timeout, None, &[])
+            .await;
+        assert!(matches!(res, Ok(None)));
+
+        let err = sift
+            .http_client
+            .post(&url, None, Some(&body), timeout, None, &[])
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                status: SiftErrorCode::InternalServerError,
+                ..
+            }
+        ));
+    }
+}
+
+#[cfg(feature = "ureq")]
+mod ureq_client {
+    use crate::common::{login, ok_response, USER_ID};
+    use futures::executor::block_on;
+    use sift_science::{events::EventOptions, Client, MockResponse, MockServer};
+
+    #[test]
+    fn track() {
+        let server = MockServer::start(vec![ok_response()]);
+        let sift = Client::new("api_key", ureq::Agent::new()).with_origin(server.origin());
+
+        block_on(sift.track(login(USER_ID), EventOptions::default())).unwrap();
+
+        let request = server.next_request();
+        assert_eq!(request.request_line, "POST /v205/events HTTP/1.1");
+        assert_eq!(request.header("Content-Type"), Some("application/json"));
+    }
+
+    #[cfg(feature = "score")]
+    #[test]
+    fn get_user_score() {
+        use sift_science::{score::ScoreOptions, AbuseType, Error, UreqClient};
+        use std::time::Duration;
+
+        let server = MockServer::start(vec![
+            MockResponse::json(
+                200,
+                r#"{"status": 0, "error_message": "OK", "scores": {"legacy": {"score": 0.42}}}"#,
+            ),
+            MockResponse::raw(
+                "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            ),
+        ]);
+        let sift = UreqClient::new("api_key", ureq::Agent::new()).with_origin(server.origin());
+
+        let opts = || ScoreOptions {
+            abuse_types: Some(vec![AbuseType::Legacy]),
+            timeout: Some(Duration::from_secs(2)),
+            ..Default::default()
+        };
+
+        let response = block_on(sift.get_user_score(USER_ID, opts())).unwrap();
+        assert_eq!(response.scores.unwrap().legacy.unwrap().score, 0.42);
+
+        let request = server.next_request();
+        assert!(request
+            .request_line
+            .starts_with("GET /v205/users/billy_jones_301/score?"));
+        assert!(request.request_line.contains("abuse_types=legacy"));
+        assert!(request
+            .header("user-agent")
+            .unwrap()
+            .starts_with("sift-rust/"));
+
+        let err = block_on(sift.get_user_score(USER_ID, opts())).unwrap_err();
+        assert!(matches!(err, Error::Http { status: 503, .. }));
+    }
+}
+
+#[cfg(feature = "surf")]
+mod surf_client {
+    use crate::common::{login, ok_response, USER_ID};
+    use futures::executor::block_on;
+    use sift_science::{
+        events::EventOptions, Client, Error, MockResponse, MockServer, SiftErrorCode, SurfClient,
+    };
+
+    #[test]
+    fn track() {
+        let server = MockServer::start(vec![
+            ok_response(),
+            MockResponse::json(400, r#"{"status": 51, "error_message": "Invalid API key"}"#),
+        ]);
+        let sift: SurfClient = Client::builder("api_key", surf::Client::new())
+            .origin(server.origin())
+            .build();
+
+        assert!(block_on(sift.track(login(USER_ID), EventOptions::default())).is_ok());
+
+        let err = block_on(sift.track(login(USER_ID), EventOptions::default())).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Request {
+                status: SiftErrorCode::InvalidApiKey,
+                ..
+            }
+        ));
+    }
+}
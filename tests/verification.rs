@@ -0,0 +1,73 @@
+mod common;
+
+use common::{ok, SESSION_ID, USER_ID};
+use serde_json::json;
+use sift_science::{
+    events::{VerificationStatus, VerificationType, VerifiedEvent},
+    verification::{SendOptions, SendRequest, SendRequestEvent},
+    Client, Method, MockHttpClient,
+};
+use std::time::Duration;
+
+#[tokio::test]
+async fn send_verification_with_options() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Post,
+        json!({
+            "status": 0,
+            "error_message": "OK",
+            "sent_at": 1566324368002u64,
+            "segment_id": "4",
+            "segment_name": "Default Template",
+            "brand_name": "",
+            "site_country": "",
+            "content_language": ""
+        }),
+    );
+    let sift = Client::new("api_key", http_client);
+
+    let req = SendRequest {
+        user_id: USER_ID.into(),
+        send_to: "billy_jones_301@gmail.com".into(),
+        verification_type: VerificationType::Email,
+        brand_name: None,
+        site_country: None,
+        event: SendRequestEvent::login(SESSION_ID),
+    };
+    let opts = SendOptions {
+        timeout: Some(Duration::from_millis(500)),
+        ..Default::default()
+    };
+
+    sift.send_verification_with_options(req, opts)
+        .await
+        .unwrap();
+
+    let request = &sift.http_client.requests()[0];
+    assert!(request.url.ends_with("/v1/verification/send"));
+    assert_eq!(request.timeout, Duration::from_millis(500));
+}
+
+#[tokio::test]
+async fn complete_verification() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(Method::Post, ok());
+    let sift = Client::new("api_key", http_client);
+
+    sift.complete_verification(
+        USER_ID,
+        SESSION_ID,
+        VerifiedEvent::Login,
+        Some(SESSION_ID.into()),
+        VerificationStatus::Success,
+    )
+    .await
+    .unwrap();
+
+    let body = &sift.http_client.bodies()[0];
+    assert_eq!(body["$type"], "$verification");
+    assert_eq!(body["$verified_event"], "$login");
+    assert_eq!(body["$verified_entity_id"], SESSION_ID);
+    assert_eq!(body["$status"], "$success");
+}
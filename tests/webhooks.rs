@@ -0,0 +1,30 @@
+use sift_science::{webhooks::parse_payload, AbuseType};
+
+fn abuse_type(decision_id: &str) -> Option<AbuseType> {
+    let body = format!(
+        r#"{{
+          "entity": {{ "type": "user", "id": "billy_jones_301" }},
+          "decision": {{ "id": "{decision_id}" }},
+          "time": 1461963439151
+        }}"#
+    );
+    parse_payload(body.as_bytes()).unwrap().abuse_type()
+}
+
+#[test]
+fn abuse_type_from_decision_id() {
+    for (decision_id, expected) in [
+        ("block_user_payment_abuse", Some(AbuseType::PaymentAbuse)),
+        (
+            "watch_user_account_takeover",
+            Some(AbuseType::AccountTakeover),
+        ),
+        ("block_user_promotion_abuse", Some(AbuseType::PromoAbuse)),
+        ("block_user_promo_abuse", Some(AbuseType::PromoAbuse)),
+        ("content_abuse", Some(AbuseType::ContentAbuse)),
+        ("hold_order_for_review", None),
+        ("looks_ok_PAYMENT_ABUSE", None),
+    ] {
+        assert_eq!(abuse_type(decision_id), expected, "{}", decision_id);
+    }
+}
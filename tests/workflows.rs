@@ -0,0 +1,44 @@
+mod common;
+
+use common::USER_ID;
+use serde_json::json;
+use sift_science::{Client, Method, MockHttpClient};
+
+#[tokio::test]
+async fn get_workflow_run() {
+    let http_client = MockHttpClient::new();
+    http_client.respond_with(
+        Method::Get,
+        json!({
+            "id": "4zxwibludiaaa",
+            "config": { "id": "5rrbr4iaaaaaa", "version": "1468367620871" },
+            "config_display_name": "workflow config",
+            "abuse_types": ["payment_abuse"],
+            "state": "running",
+            "entity": { "id": USER_ID, "type": "user" },
+            "history": [
+                {
+                    "app": "decision",
+                    "name": "decision",
+                    "state": "running",
+                    "config": { "decision_id": "user-looks-ok-payment-abuse" }
+                },
+                { "app": "user_scorer", "name": "user scorer", "state": "finished" }
+            ]
+        }),
+    );
+    let sift = Client::new("api_key", http_client).with_account_id("5c9e6c1b8bd9b44e");
+
+    let run = sift.get_workflow_run("4zxwibludiaaa").await.unwrap();
+    assert_eq!(run.state, "running");
+    assert_eq!(run.entity.id, USER_ID);
+    assert_eq!(run.entity.entity_type, "user");
+    assert_eq!(run.config.unwrap().id.as_deref(), Some("5rrbr4iaaaaaa"));
+    assert_eq!(run.history[0].app, "decision");
+    assert_eq!(run.history[1].state, "finished");
+
+    sift.http_client.assert_requested(
+        Method::Get,
+        "/v3/accounts/5c9e6c1b8bd9b44e/workflows/runs/4zxwibludiaaa",
+    );
+}